@@ -0,0 +1,37 @@
+use criterion::{black_box, criterion_group, criterion_main, Criterion};
+use unixstring::UnixString;
+
+const MIB: usize = 1024 * 1024;
+
+fn from_bytes(c: &mut Criterion) {
+    let bytes = vec![b'a'; MIB];
+
+    c.bench_function("from_bytes (1 MiB)", |b| {
+        b.iter(|| UnixString::from_bytes(black_box(bytes.clone())))
+    });
+}
+
+fn push_bytes(c: &mut Criterion) {
+    let bytes = vec![b'a'; MIB];
+
+    c.bench_function("push_bytes (1 MiB)", |b| {
+        b.iter(|| {
+            let mut unx = UnixString::with_capacity(MIB);
+            unx.push_bytes(black_box(&bytes)).unwrap();
+            unx
+        })
+    });
+}
+
+fn find_byte(c: &mut Criterion) {
+    let mut bytes = vec![b'a'; MIB];
+    bytes[MIB - 1] = b'b';
+    let unx = UnixString::from_bytes(bytes).unwrap();
+
+    c.bench_function("find single byte (1 MiB)", |b| {
+        b.iter(|| black_box(&unx).find(b"b"))
+    });
+}
+
+criterion_group!(benches, from_bytes, push_bytes, find_byte);
+criterion_main!(benches);
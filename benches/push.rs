@@ -0,0 +1,43 @@
+use std::ffi::OsString;
+
+use criterion::{black_box, criterion_group, criterion_main, Criterion};
+use unixstring::UnixString;
+
+const MIB: usize = 1024 * 1024;
+
+fn push_small_segments(c: &mut Criterion) {
+    let segments: Vec<String> = (0..10_000).map(|i| format!("/{}", i)).collect();
+
+    c.bench_function("push_bytes (10k small segments)", |b| {
+        b.iter(|| {
+            let mut unx = UnixString::new();
+            for segment in &segments {
+                unx.push_bytes(black_box(segment.as_bytes())).unwrap();
+            }
+            unx
+        })
+    });
+}
+
+fn append_large_os_str(c: &mut Criterion) {
+    let os_string = OsString::from("a".repeat(MIB));
+
+    c.bench_function("push (1 MiB OsStr)", |b| {
+        b.iter(|| {
+            let mut unx = UnixString::from_string("/".into()).unwrap();
+            unx.push(black_box(&os_string)).unwrap();
+            unx
+        })
+    });
+
+    c.bench_function("extend_from_os_str (1 MiB OsStr)", |b| {
+        b.iter(|| {
+            let mut unx = UnixString::from_string("/".into()).unwrap();
+            unx.extend_from_os_str(black_box(&os_string)).unwrap();
+            unx
+        })
+    });
+}
+
+criterion_group!(benches, push_small_segments, append_large_os_str);
+criterion_main!(benches);
@@ -0,0 +1,25 @@
+use criterion::{black_box, criterion_group, criterion_main, Criterion};
+use unixstring::UnixString;
+
+const MIB: usize = 1024 * 1024;
+
+fn validate(c: &mut Criterion) {
+    let unx = UnixString::from_bytes(vec![b'a'; MIB]).unwrap();
+
+    c.bench_function("validate (1 MiB, valid)", |b| {
+        b.iter(|| black_box(&unx).validate())
+    });
+}
+
+fn validate_missing_terminator(c: &mut Criterion) {
+    let mut unx = UnixString::from_bytes(vec![b'a'; MIB]).unwrap();
+    // Overwrite the nul terminator, mocking a buggy FFI write
+    unsafe { unx.as_mut_ptr().add(MIB).write(b'a' as _) };
+
+    c.bench_function("validate (1 MiB, missing terminator)", |b| {
+        b.iter(|| black_box(&unx).validate())
+    });
+}
+
+criterion_group!(benches, validate, validate_missing_terminator);
+criterion_main!(benches);
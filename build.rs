@@ -0,0 +1,25 @@
+use std::env;
+
+// Platforms whose libc provides `memrchr`, which is only then exposed by the `libc` crate
+const LIBC_MEMRCHR_TARGET_OSES: &[&str] = &[
+    "linux",
+    "android",
+    "emscripten",
+    "freebsd",
+    "dragonfly",
+    "netbsd",
+    "openbsd",
+];
+
+fn main() {
+    println!("cargo:rerun-if-changed=build.rs");
+    println!("cargo:rustc-check-cfg=cfg(libc_memrchr)");
+
+    let libc_memchr = env::var_os("CARGO_FEATURE_LIBC_MEMCHR").is_some();
+    let target_os = env::var("CARGO_CFG_TARGET_OS").unwrap_or_default();
+
+    // Set when `memrchr` should be delegated to `libc::memrchr` instead of using the pure-Rust implementation
+    if libc_memchr && LIBC_MEMRCHR_TARGET_OSES.contains(&target_os.as_str()) {
+        println!("cargo:rustc-cfg=libc_memrchr");
+    }
+}
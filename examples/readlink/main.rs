@@ -0,0 +1,13 @@
+use std::{convert::TryFrom, env};
+
+use unixstring::UnixString;
+
+fn main() -> std::io::Result<()> {
+    for arg in env::args_os().skip(1).flat_map(UnixString::try_from) {
+        let target = arg.read_link()?;
+
+        println!("{} -> {}", arg, target);
+    }
+
+    Ok(())
+}
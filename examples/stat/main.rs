@@ -15,7 +15,7 @@ fn stat(path: &UnixString) -> std::io::Result<libc::stat> {
 }
 
 fn main() -> std::io::Result<()> {
-    for arg in env::args_os().map(UnixString::try_from).flatten() {
+    for arg in env::args_os().flat_map(UnixString::try_from) {
         let stat = stat(&arg)?;
 
         let size = stat.st_size;
@@ -1,10 +1,10 @@
-use std::{
-    ffi::{CStr, OsStr},
-    path::Path,
-};
+use core::ffi::CStr;
+#[cfg(all(feature = "std", unix))]
+use std::{ffi::OsStr, path::Path};
 
 use crate::UnixString;
 
+#[cfg(all(feature = "std", unix))]
 impl AsRef<Path> for UnixString {
     fn as_ref(&self) -> &Path {
         self.as_path()
@@ -17,6 +17,7 @@ impl AsRef<CStr> for UnixString {
     }
 }
 
+#[cfg(all(feature = "std", unix))]
 impl AsRef<OsStr> for UnixString {
     fn as_ref(&self) -> &OsStr {
         self.as_os_str()
@@ -0,0 +1,14 @@
+use core::borrow::Borrow;
+
+use crate::UnixString;
+
+// Note: `Borrow` requires that `Hash`, `Eq` and `Ord` behave identically for the owned and borrowed values.
+// This holds since `UnixString` hashes its bytes *without* the nul terminator, just like `as_bytes` returns them,
+// and since a `UnixString` has no interior zero bytes, comparing with or without the nul terminator gives the same ordering.
+//
+// `Borrow<CStr>` is not implemented, as `CStr` hashes its bytes *with* the nul terminator.
+impl Borrow<[u8]> for UnixString {
+    fn borrow(&self) -> &[u8] {
+        self.as_bytes()
+    }
+}
@@ -0,0 +1,23 @@
+use bstr::BStr;
+
+use crate::UnixString;
+
+impl UnixString {
+    /// Gets a [`BStr`](bstr::BStr) view over the bytes of this `UnixString`, *without* the nul terminator.
+    ///
+    /// This is a zero-cost conversion which gives access to `bstr`'s formatting and searching over
+    /// content that may not be valid UTF-8.
+    ///
+    /// Only available with the `bstr` feature enabled.
+    ///
+    /// ```rust
+    /// use unixstring::UnixString;
+    ///
+    /// let unx = UnixString::from_bytes(b"/home/\xFFuser".to_vec()).unwrap();
+    ///
+    /// assert_eq!(format!("{:?}", unx.as_bstr()), r#""/home/\xffuser""#);
+    /// ```
+    pub fn as_bstr(&self) -> &BStr {
+        BStr::new(self.as_bytes())
+    }
+}
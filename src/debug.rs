@@ -0,0 +1,18 @@
+use alloc::string::String;
+use core::fmt::{self, Debug};
+
+use crate::UnixString;
+
+impl Debug for UnixString {
+    /// Formats the `UnixString` as `UnixString("...")`, with its nul terminator omitted.
+    ///
+    /// Invalid UTF-8 sequences are replaced with the Unicode replacement character, and
+    /// non-printable characters are escaped just like in `str`'s `Debug` implementation.
+    ///
+    /// This never panics, even if the contents were invalidated through [`UnixString::as_mut_ptr`](UnixString::as_mut_ptr).
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_tuple("UnixString")
+            .field(&&*String::from_utf8_lossy(self.as_bytes()))
+            .finish()
+    }
+}
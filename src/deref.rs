@@ -0,0 +1,25 @@
+use core::{ffi::CStr, ops::Deref};
+
+use crate::UnixString;
+
+/// A `UnixString` dereferences to a [`CStr`], so all of `CStr`'s methods can be called directly on it.
+///
+/// Do note that the inherent methods of `UnixString` take precedence over the ones of `CStr`.
+/// For example, [`UnixString::to_str`](UnixString::to_str) returns this crate's [`Result`](crate::Result)
+/// instead of the `Result<&str, Utf8Error>` returned by [`CStr::to_str`].
+///
+/// ```rust
+/// use unixstring::UnixString;
+///
+/// let unx = UnixString::from_string("/home/user".into()).unwrap();
+///
+/// assert_eq!(unx.to_bytes(), b"/home/user");
+/// assert_eq!(unx.to_bytes_with_nul(), b"/home/user\0");
+/// ```
+impl Deref for UnixString {
+    type Target = CStr;
+
+    fn deref(&self) -> &CStr {
+        self.as_c_str()
+    }
+}
@@ -0,0 +1,16 @@
+use alloc::string::String;
+use core::fmt::{self, Display};
+
+use crate::UnixString;
+
+impl Display for UnixString {
+    /// Formats the `UnixString` with its nul terminator omitted.
+    ///
+    /// Invalid UTF-8 sequences are replaced with the Unicode replacement character, just like
+    /// in [`UnixString::to_string_lossy`](UnixString::to_string_lossy).
+    ///
+    /// This never panics, even if the contents were invalidated through [`UnixString::as_mut_ptr`](UnixString::as_mut_ptr).
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        Display::fmt(&*String::from_utf8_lossy(self.as_bytes()), f)
+    }
+}
@@ -1,27 +1,50 @@
-use std::fmt::Display;
+use core::fmt::Display;
 
 /// An error enum that encapsulates all possible errors in this crate.
+///
+/// The set of variants depends on the enabled features (e.g. `Io` requires `std`) and may grow in the future,
+/// so matching on it must include a wildcard arm.
 #[derive(Debug)]
+#[non_exhaustive]
 pub enum Error {
-    InteriorNulByte,
+    /// A zero byte was found somewhere other than at the end of the given data.
+    InteriorNulByte {
+        /// The offset of the offending zero byte within the given data.
+        position: usize,
+    },
     MissingNulTerminator,
-    IntoUtf8(std::str::Utf8Error),
-    FromUtf8(std::string::FromUtf8Error),
-    //#[error("IO error: {0}")]
+    IntoUtf8(core::str::Utf8Error),
+    FromUtf8(alloc::string::FromUtf8Error),
+    /// A memory allocation could not be made, e.g. because the requested capacity was too large.
+    Allocation(alloc::collections::TryReserveError),
+    #[cfg(feature = "std")]
     Io(std::io::Error),
 }
 
-impl std::error::Error for Error {}
+#[cfg(feature = "std")]
+impl std::error::Error for Error {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            Error::InteriorNulByte { .. } | Error::MissingNulTerminator => None,
+            Error::IntoUtf8(err) => Some(err),
+            Error::FromUtf8(err) => Some(err),
+            Error::Allocation(err) => Some(err),
+            Error::Io(err) => Some(err),
+        }
+    }
+}
 
-/// A [`Result`](std::result::Result) type alias for this crate’s [`Error`] type.
-pub type Result<T> = std::result::Result<T, Error>;
+/// A [`Result`](core::result::Result) type alias for this crate’s [`Error`] type.
+pub type Result<T> = core::result::Result<T, Error>;
 
 impl Display for Error {
-    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         match self {
-            Error::InteriorNulByte => {
-                write!(f, "Interior zero byte found during CString construction")
-            }
+            Error::InteriorNulByte { position } => write!(
+                f,
+                "Interior zero byte found at position {} during CString construction",
+                position
+            ),
             Error::IntoUtf8(err) => write!(
                 f,
                 "Failed to interpret a sequence of bytes as a string,: {0}",
@@ -35,19 +58,59 @@ impl Display for Error {
             Error::MissingNulTerminator => {
                 write!(f, "Invalid UnixString found: missing a nul terminator")
             }
+            Error::Allocation(err) => write!(f, "Failed to allocate memory: {}", err),
+            #[cfg(feature = "std")]
             Error::Io(err) => write!(f, "IO error: {}", err),
         }
     }
 }
 
-impl From<std::str::Utf8Error> for Error {
-    fn from(err: std::str::Utf8Error) -> Self {
+/// Two `Error`s are equal if they are the same variant and the data they hold, if any, is equal.
+///
+/// Since [`std::io::Error`] does not implement `PartialEq`, two `Error::Io` are considered equal if
+/// they have the same [`ErrorKind`](std::io::ErrorKind) and the same raw OS error code, if any.
+impl PartialEq for Error {
+    fn eq(&self, other: &Self) -> bool {
+        match (self, other) {
+            (Error::InteriorNulByte { position }, Error::InteriorNulByte { position: other }) => {
+                position == other
+            }
+            (Error::MissingNulTerminator, Error::MissingNulTerminator) => true,
+            (Error::IntoUtf8(err), Error::IntoUtf8(other)) => err == other,
+            (Error::FromUtf8(err), Error::FromUtf8(other)) => err == other,
+            (Error::Allocation(err), Error::Allocation(other)) => err == other,
+            #[cfg(feature = "std")]
+            (Error::Io(err), Error::Io(other)) => {
+                err.kind() == other.kind() && err.raw_os_error() == other.raw_os_error()
+            }
+            _ => false,
+        }
+    }
+}
+
+impl Eq for Error {}
+
+impl From<core::str::Utf8Error> for Error {
+    fn from(err: core::str::Utf8Error) -> Self {
         Self::IntoUtf8(err)
     }
 }
 
-impl From<std::string::FromUtf8Error> for Error {
-    fn from(err: std::string::FromUtf8Error) -> Self {
+impl From<alloc::string::FromUtf8Error> for Error {
+    fn from(err: alloc::string::FromUtf8Error) -> Self {
         Self::FromUtf8(err)
     }
 }
+
+impl From<alloc::collections::TryReserveError> for Error {
+    fn from(err: alloc::collections::TryReserveError) -> Self {
+        Self::Allocation(err)
+    }
+}
+
+#[cfg(feature = "std")]
+impl From<std::io::Error> for Error {
+    fn from(err: std::io::Error) -> Self {
+        Self::Io(err)
+    }
+}
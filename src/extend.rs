@@ -0,0 +1,51 @@
+#[cfg(all(feature = "std", unix))]
+use std::{ffi::OsStr, path::PathBuf};
+
+use crate::UnixString;
+
+impl Extend<u8> for UnixString {
+    /// Extends the `UnixString` with the bytes yielded by the given iterator.
+    ///
+    /// # Panics
+    ///
+    /// Since `Extend` is infallible, this panics if the iterator yields an interior zero byte.
+    /// In that case `self` is left unchanged.
+    ///
+    /// See [`UnixString::try_extend`](UnixString::try_extend) for a fallible version of this method.
+    fn extend<I: IntoIterator<Item = u8>>(&mut self, iter: I) {
+        self.try_extend(iter)
+            .expect("interior zero byte found while extending a UnixString")
+    }
+}
+
+#[cfg(all(feature = "std", unix))]
+impl<'a> Extend<&'a OsStr> for UnixString {
+    /// Appends each of the `OsStr`s yielded by the given iterator, with no separator between them.
+    ///
+    /// # Panics
+    ///
+    /// Since `Extend` is infallible, this panics if any of the items contains an interior zero byte.
+    /// In that case, `self` holds every item yielded before the offending one.
+    ///
+    /// See [`UnixString::try_extend_os`](UnixString::try_extend_os) for a fallible version of this method.
+    fn extend<I: IntoIterator<Item = &'a OsStr>>(&mut self, iter: I) {
+        self.try_extend_os(iter)
+            .expect("interior zero byte found while extending a UnixString")
+    }
+}
+
+#[cfg(all(feature = "std", unix))]
+impl Extend<PathBuf> for UnixString {
+    /// Appends each of the `PathBuf`s yielded by the given iterator, with no separator between them.
+    ///
+    /// # Panics
+    ///
+    /// Since `Extend` is infallible, this panics if any of the items contains an interior zero byte.
+    /// In that case, `self` holds every item yielded before the offending one.
+    ///
+    /// See [`UnixString::try_extend_os`](UnixString::try_extend_os) for a fallible version of this method.
+    fn extend<I: IntoIterator<Item = PathBuf>>(&mut self, iter: I) {
+        self.try_extend_os(iter)
+            .expect("interior zero byte found while extending a UnixString")
+    }
+}
@@ -1,7 +1,6 @@
-use std::{
-    ffi::{CString, OsString},
-    path::PathBuf,
-};
+use alloc::ffi::CString;
+#[cfg(all(feature = "std", unix))]
+use std::{ffi::OsString, path::PathBuf};
 
 use crate::UnixString;
 
@@ -15,6 +14,7 @@ impl From<UnixString> for CString {
     }
 }
 
+#[cfg(all(feature = "std", unix))]
 impl From<UnixString> for OsString {
     fn from(unx: UnixString) -> Self {
         use std::os::unix::prelude::OsStringExt;
@@ -25,6 +25,7 @@ impl From<UnixString> for OsString {
     }
 }
 
+#[cfg(all(feature = "std", unix))]
 impl From<UnixString> for PathBuf {
     fn from(unx: UnixString) -> Self {
         let os_string = unx.into_os_string();
@@ -0,0 +1,31 @@
+use core::iter::FromIterator;
+
+use crate::UnixString;
+
+impl FromIterator<u8> for UnixString {
+    /// Creates a `UnixString` from the bytes yielded by the given iterator.
+    ///
+    /// # Panics
+    ///
+    /// Since `FromIterator` is infallible, this panics if the iterator yields an interior zero byte.
+    /// A zero byte yielded as the very last element is accepted as the nul terminator.
+    ///
+    /// See [`UnixString::try_from_bytes_iter`](UnixString::try_from_bytes_iter) for a fallible version of this method.
+    fn from_iter<I: IntoIterator<Item = u8>>(iter: I) -> Self {
+        let mut unx = UnixString::new();
+        unx.extend(iter);
+        unx
+    }
+}
+
+impl<'a> FromIterator<&'a u8> for UnixString {
+    /// Creates a `UnixString` from the bytes yielded by the given iterator.
+    ///
+    /// # Panics
+    ///
+    /// Since `FromIterator` is infallible, this panics if the iterator yields an interior zero byte.
+    /// A zero byte yielded as the very last element is accepted as the nul terminator.
+    fn from_iter<I: IntoIterator<Item = &'a u8>>(iter: I) -> Self {
+        iter.into_iter().copied().collect()
+    }
+}
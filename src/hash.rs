@@ -0,0 +1,30 @@
+use core::hash::{Hash, Hasher};
+
+use crate::UnixString;
+
+/// A `UnixString` hashes only its bytes *without* the nul terminator, i.e. exactly like
+/// [`UnixString::as_bytes`](UnixString::as_bytes) does.
+///
+/// This allows looking up `UnixString`s in hash-based collections through a `&[u8]`, given the
+/// [`Borrow<[u8]>`](core::borrow::Borrow) implementation. Do note that this means that a `UnixString`
+/// does *not* hash the same as its [`CStr`](core::ffi::CStr) counterpart, which includes the nul terminator,
+/// so a lookup through a `&CStr` must pass its bytes, obtained with [`CStr::to_bytes`](core::ffi::CStr::to_bytes).
+///
+/// ```rust
+/// use std::{collections::HashSet, ffi::CStr};
+///
+/// use unixstring::UnixString;
+///
+/// let mut set = HashSet::new();
+/// set.insert(UnixString::from_string("/etc/passwd".into()).unwrap());
+///
+/// assert!(set.contains(&b"/etc/passwd"[..]));
+///
+/// let cstr = CStr::from_bytes_with_nul(b"/etc/passwd\0").unwrap();
+/// assert!(set.contains(cstr.to_bytes()));
+/// ```
+impl Hash for UnixString {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.as_bytes().hash(state)
+    }
+}
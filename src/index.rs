@@ -0,0 +1,57 @@
+use core::ops::{Index, Range, RangeFrom, RangeFull, RangeInclusive, RangeTo};
+
+use crate::UnixString;
+
+/// Indexes into the bytes of the `UnixString`, excluding its nul terminator.
+///
+/// # Panics
+///
+/// Panics if the range is out of bounds of [`UnixString::as_bytes`](UnixString::as_bytes),
+/// so the nul terminator can never be reached through indexing.
+///
+/// ```rust
+/// use unixstring::UnixString;
+///
+/// let unx = UnixString::from_string("/home/user".into()).unwrap();
+///
+/// assert_eq!(&unx[1..5], b"home");
+/// ```
+impl Index<Range<usize>> for UnixString {
+    type Output = [u8];
+
+    fn index(&self, index: Range<usize>) -> &[u8] {
+        &self.as_bytes()[index]
+    }
+}
+
+impl Index<RangeTo<usize>> for UnixString {
+    type Output = [u8];
+
+    fn index(&self, index: RangeTo<usize>) -> &[u8] {
+        &self.as_bytes()[index]
+    }
+}
+
+impl Index<RangeFrom<usize>> for UnixString {
+    type Output = [u8];
+
+    fn index(&self, index: RangeFrom<usize>) -> &[u8] {
+        &self.as_bytes()[index]
+    }
+}
+
+impl Index<RangeFull> for UnixString {
+    type Output = [u8];
+
+    fn index(&self, _index: RangeFull) -> &[u8] {
+        self.as_bytes()
+    }
+}
+
+impl Index<RangeInclusive<usize>> for UnixString {
+    type Output = [u8];
+
+    fn index(&self, index: RangeInclusive<usize>) -> &[u8] {
+        &self.as_bytes()[index]
+    }
+}
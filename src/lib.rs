@@ -34,11 +34,18 @@
 //! |    From    |            Potential failure            | Trait impl |           Function           |
 //! |:----------:|:---------------------------------------:|:----------:|:----------------------------:|
 //! | `CString`  |               Infallible                |    From    |  `UnixString::from_cstring`  |
+//! |  `&CStr`   |               Infallible                |    From    |                              |
 //! | `PathBuf`  | Fails if contains an interior zero byte |  TryFrom   |  `UnixString::from_pathbuf`  |
 //! |  `String`  | Fails if contains an interior zero byte |  TryFrom   |  `UnixString::from_string`   |
+//! |   `&str`   | Fails if contains an interior zero byte |  TryFrom   |                              |
 //! | `Vec<u8>`  | Fails if contains an interior zero byte |  TryFrom   |   `UnixString::from_bytes`   |
+//! | `Vec<u8>`  | Infallible: truncated at the first zero byte |  None   | `UnixString::from_bytes_lossy` |
+//! |  `&[u8]`   | Fails if contains an interior zero byte |  TryFrom   |                              |
 //! | `OsString` | Fails if contains an interior zero byte |  TryFrom   | `UnixString::from_os_string` |
+//! |  `&OsStr`  | Fails if contains an interior zero byte |    None    |   `UnixString::from_os_str`  |
+//! |  `&Path`   | Fails if contains an interior zero byte |    None    |    `UnixString::from_path`   |
 //! | `* const c_char` | Unsafe, see the docs for more info|  None      | `UnixString::from_ptr`       |
+//! | `* const c_char` and length | Unsafe, fails if contains an interior zero byte |  None | `UnixString::from_ptr_with_len` |
 //!
 //! ## Converting from an UnixString
 //!
@@ -56,14 +63,67 @@
 //! | `Vec<u8>`  |  `UnixString::into_bytes_with_nul`  |     Returns the bytes of the `UnixString` with the null terminator     |
 //!
 //! All of the above are also available through `.into()`.
+//!
+//! ## `no_std` support
+//!
+//! `UnixString` can be used without the standard library, as long as `alloc` is available, by disabling the default `std` feature.
+//!
+//! In that case, everything that relies on `Path`, `OsStr`, their owned counterparts or `std::io` is unavailable,
+//! while the core byte and [`CStr`](core::ffi::CStr) functionality (e.g. `from_bytes`, `push_bytes`, `as_bytes`, `as_c_str` and `as_ptr`) is kept.
+//!
+//! ## Non-Unix platforms
+//!
+//! The crate also builds on non-Unix targets, such as Windows, so that cross-platform FFI code relying only on its core still compiles.
+//!
+//! There, `Path` and `OsStr` can't be converted to and from raw bytes, so everything that relies on them is unavailable,
+//! just as if the `std` feature were disabled. The core byte and `CStr` functionality, as well as `io::Write`, is kept.
+//!
+//! ## Looking up an UnixString by its bytes
+//!
+//! `UnixString` implements `Borrow<[u8]>` and hashes just like its bytes without the nul terminator,
+//! so collections such as `HashMap<UnixString, _>` or `BTreeSet<UnixString>` can be queried with a `&[u8]`.
+//!
+//! Earlier versions also implemented `Borrow<CStr>`, which was removed since `CStr` hashes its nul terminator as well.
+//! Lookups through a `&CStr` should now go through its bytes, i.e. `map.get(cstr)` becomes `map.get(cstr.to_bytes())`:
+//!
+//! ```rust
+//! use std::{collections::HashMap, ffi::CStr};
+//!
+//! use unixstring::UnixString;
+//!
+//! let mut map = HashMap::new();
+//! map.insert(UnixString::from_string("/etc/passwd".into()).unwrap(), 1);
+//!
+//! let cstr = CStr::from_bytes_with_nul(b"/etc/passwd\0").unwrap();
+//! assert_eq!(map.get(cstr.to_bytes()), Some(&1));
+//! ```
+
+#![cfg_attr(not(any(feature = "std", test)), no_std)]
+
+extern crate alloc;
 
 mod as_ref;
+mod borrow;
+#[cfg(feature = "bstr")]
+mod bstr_impl;
+mod debug;
+mod deref;
+mod display;
 mod error;
+mod extend;
 mod from;
+mod from_iterator;
+mod hash;
+mod index;
 mod memchr;
 mod partial_eq;
+mod partial_ord;
+mod path;
+#[cfg(feature = "serde")]
+mod serde_impl;
 mod try_from;
 mod unix_string;
+mod write;
 
 pub use error::{Error, Result};
 pub use unix_string::UnixString;
@@ -1,3 +1,8 @@
+// Amount of bytes checked at once by the pure-Rust `memchr` and `memrchr`
+#[cfg(not(libc_memrchr))]
+const BLOCK_SIZE: usize = 64;
+
+#[cfg(feature = "libc-memchr")]
 pub fn memchr(needle: u8, haystack: &[u8]) -> Option<usize> {
     let p = unsafe {
         libc::memchr(
@@ -13,6 +18,63 @@ pub fn memchr(needle: u8, haystack: &[u8]) -> Option<usize> {
     }
 }
 
+#[cfg(not(feature = "libc-memchr"))]
+pub fn memchr(needle: u8, haystack: &[u8]) -> Option<usize> {
+    // Scan a block at a time until we find a block that contains the needle.
+    // Not branching on each byte of the block allows the compiler to vectorize this check.
+    let mut offset = 0;
+    for block in haystack.chunks_exact(BLOCK_SIZE) {
+        if block
+            .iter()
+            .fold(false, |found, &byte| found | (byte == needle))
+        {
+            break;
+        }
+        offset += BLOCK_SIZE;
+    }
+
+    // Find the exact position of the needle within the remaining bytes
+    haystack[offset..]
+        .iter()
+        .position(|&byte| byte == needle)
+        .map(|pos| offset + pos)
+}
+
+// `libc_memrchr` is set by the build script when the `libc-memchr` feature is enabled and `libc` exposes `memrchr` for the target
+#[cfg(libc_memrchr)]
+pub fn memrchr(needle: u8, haystack: &[u8]) -> Option<usize> {
+    let p = unsafe {
+        libc::memrchr(
+            haystack.as_ptr() as *const libc::c_void,
+            needle as libc::c_int,
+            haystack.len(),
+        )
+    };
+    if p.is_null() {
+        None
+    } else {
+        Some(p as usize - (haystack.as_ptr() as usize))
+    }
+}
+
+#[cfg(not(libc_memrchr))]
+pub fn memrchr(needle: u8, haystack: &[u8]) -> Option<usize> {
+    // Scan a block at a time, from the end, until we find a block that contains the needle
+    let mut end = haystack.len();
+    for block in haystack.rchunks_exact(BLOCK_SIZE) {
+        if block
+            .iter()
+            .fold(false, |found, &byte| found | (byte == needle))
+        {
+            break;
+        }
+        end -= BLOCK_SIZE;
+    }
+
+    // Find the exact position of the needle within the remaining bytes
+    haystack[..end].iter().rposition(|&byte| byte == needle)
+}
+
 pub fn find_nul_byte(bytes: &[u8]) -> Option<usize> {
     memchr(0, bytes)
 }
@@ -20,7 +82,7 @@ pub fn find_nul_byte(bytes: &[u8]) -> Option<usize> {
 #[cfg(test)]
 mod tests {
 
-    use super::memchr;
+    use super::{memchr, memrchr};
 
     #[test]
     fn memchr_() {
@@ -31,4 +93,59 @@ mod tests {
         dbg!(memchr(0, text.as_bytes()));
         assert!(matches!(memchr(0, text.as_bytes()), Some(11)));
     }
+
+    #[test]
+    fn memchr_every_position() {
+        for len in 0..150 {
+            let mut haystack = vec![b'a'; len];
+            assert_eq!(memchr(b'b', &haystack), None);
+
+            for pos in 0..len {
+                haystack[pos] = b'b';
+                assert_eq!(memchr(b'b', &haystack), Some(pos));
+                assert_eq!(memchr(b'b', &haystack[pos..]), Some(0));
+                haystack[pos] = b'a';
+            }
+        }
+    }
+
+    #[test]
+    fn memchr_first_occurrence() {
+        let haystack = b"0123456789abcdef\xff0123456789\xff";
+        assert_eq!(memchr(0xff, haystack), Some(16));
+        assert_eq!(memchr(b'9', haystack), Some(9));
+        assert_eq!(memchr(b'f', haystack), Some(15));
+    }
+
+    #[test]
+    fn memrchr_() {
+        let text = "textwithoutnulbytes";
+        assert!(memrchr(0, text.as_bytes()).is_none());
+
+        let text = "text\0with\0nulbytes";
+        assert!(matches!(memrchr(0, text.as_bytes()), Some(9)));
+    }
+
+    #[test]
+    fn memrchr_last_occurrence() {
+        let haystack = b"\xff0123456789abcdef\xff0123456789";
+        assert_eq!(memrchr(0xff, haystack), Some(17));
+        assert_eq!(memrchr(b'0', haystack), Some(18));
+        assert_eq!(memrchr(b'f', haystack), Some(16));
+    }
+
+    #[test]
+    fn memrchr_every_position() {
+        for len in 0..150 {
+            let mut haystack = vec![b'a'; len];
+            assert_eq!(memrchr(b'b', &haystack), None);
+
+            for pos in 0..len {
+                haystack[pos] = b'b';
+                assert_eq!(memrchr(b'b', &haystack), Some(pos));
+                assert_eq!(memrchr(b'b', &haystack[..=pos]), Some(pos));
+                haystack[pos] = b'a';
+            }
+        }
+    }
 }
@@ -1,16 +1,21 @@
+use alloc::{ffi::CString, string::String, vec::Vec};
+use core::ffi::CStr;
+#[cfg(all(feature = "std", unix))]
 use std::{
-    ffi::{CStr, OsStr},
-    path::Path,
+    ffi::{OsStr, OsString},
+    path::{Path, PathBuf},
 };
 
 use crate::UnixString;
 
+#[cfg(all(feature = "std", unix))]
 impl PartialEq<&Path> for UnixString {
     fn eq(&self, other: &&Path) -> bool {
         self.as_path() == *other
     }
 }
 
+#[cfg(all(feature = "std", unix))]
 impl PartialEq<UnixString> for &Path {
     fn eq(&self, other: &UnixString) -> bool {
         other == self
@@ -33,12 +38,14 @@ impl PartialEq<UnixString> for &str {
     }
 }
 
+#[cfg(all(feature = "std", unix))]
 impl PartialEq<&OsStr> for UnixString {
     fn eq(&self, other: &&OsStr) -> bool {
         self.as_os_str() == *other
     }
 }
 
+#[cfg(all(feature = "std", unix))]
 impl PartialEq<UnixString> for &OsStr {
     fn eq(&self, other: &UnixString) -> bool {
         other == self
@@ -56,3 +63,113 @@ impl PartialEq<UnixString> for &CStr {
         other == self
     }
 }
+
+impl PartialEq<&UnixString> for UnixString {
+    fn eq(&self, other: &&UnixString) -> bool {
+        self == *other
+    }
+}
+
+impl PartialEq<UnixString> for &UnixString {
+    fn eq(&self, other: &UnixString) -> bool {
+        *self == other
+    }
+}
+
+#[cfg(all(feature = "std", unix))]
+impl PartialEq<PathBuf> for UnixString {
+    fn eq(&self, other: &PathBuf) -> bool {
+        self.as_path() == other
+    }
+}
+
+#[cfg(all(feature = "std", unix))]
+impl PartialEq<UnixString> for PathBuf {
+    fn eq(&self, other: &UnixString) -> bool {
+        other == self
+    }
+}
+
+impl PartialEq<String> for UnixString {
+    /// Does a byte-level comparison to a `String`.
+    /// This function does not at all suppose that `self` is valid UTF-8, but this does not make this comparison unsafe.
+    fn eq(&self, other: &String) -> bool {
+        self.as_bytes() == other.as_bytes()
+    }
+}
+
+impl PartialEq<UnixString> for String {
+    /// Does a byte-level comparison to a `String`.
+    /// This function does not at all suppose that `self` is valid UTF-8, but this does not make this comparison unsafe.
+    fn eq(&self, other: &UnixString) -> bool {
+        other == self
+    }
+}
+
+#[cfg(all(feature = "std", unix))]
+impl PartialEq<OsString> for UnixString {
+    fn eq(&self, other: &OsString) -> bool {
+        self.as_os_str() == other
+    }
+}
+
+#[cfg(all(feature = "std", unix))]
+impl PartialEq<UnixString> for OsString {
+    fn eq(&self, other: &UnixString) -> bool {
+        other == self
+    }
+}
+
+impl PartialEq<CString> for UnixString {
+    fn eq(&self, other: &CString) -> bool {
+        self.as_c_str() == other.as_c_str()
+    }
+}
+
+impl PartialEq<UnixString> for CString {
+    fn eq(&self, other: &UnixString) -> bool {
+        other == self
+    }
+}
+
+impl PartialEq<Vec<u8>> for UnixString {
+    /// Does a byte-level comparison to a `Vec<u8>`, not considering the nul terminator of `self`.
+    fn eq(&self, other: &Vec<u8>) -> bool {
+        self.as_bytes() == other.as_slice()
+    }
+}
+
+impl PartialEq<UnixString> for Vec<u8> {
+    /// Does a byte-level comparison to a `UnixString`, not considering its nul terminator.
+    fn eq(&self, other: &UnixString) -> bool {
+        other == self
+    }
+}
+
+impl PartialEq<&[u8]> for UnixString {
+    /// Does a byte-level comparison to a byte slice, not considering the nul terminator of `self`.
+    fn eq(&self, other: &&[u8]) -> bool {
+        self.as_bytes() == *other
+    }
+}
+
+impl PartialEq<UnixString> for &[u8] {
+    /// Does a byte-level comparison to a `UnixString`, not considering its nul terminator.
+    fn eq(&self, other: &UnixString) -> bool {
+        other == self
+    }
+}
+
+impl<const N: usize> PartialEq<[u8; N]> for UnixString {
+    /// Does a byte-level comparison to a byte array, not considering the nul terminator of `self`.
+    fn eq(&self, other: &[u8; N]) -> bool {
+        self.as_bytes() == other
+    }
+}
+
+impl<const N: usize> PartialEq<UnixString> for [u8; N] {
+    /// Does a byte-level comparison to a `UnixString`, not considering its nul terminator.
+    fn eq(&self, other: &UnixString) -> bool {
+        other == self
+    }
+}
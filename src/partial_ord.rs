@@ -0,0 +1,67 @@
+use core::{cmp::Ordering, ffi::CStr};
+#[cfg(all(feature = "std", unix))]
+use std::{ffi::OsStr, os::unix::prelude::OsStrExt, path::Path};
+
+use crate::UnixString;
+
+impl PartialOrd<&str> for UnixString {
+    /// Does a byte-level comparison to a string slice, consistent with the ordering between `UnixString`s.
+    fn partial_cmp(&self, other: &&str) -> Option<Ordering> {
+        self.as_bytes().partial_cmp(other.as_bytes())
+    }
+}
+
+impl PartialOrd<UnixString> for &str {
+    /// Does a byte-level comparison to a `UnixString`, consistent with the ordering between `UnixString`s.
+    fn partial_cmp(&self, other: &UnixString) -> Option<Ordering> {
+        self.as_bytes().partial_cmp(other.as_bytes())
+    }
+}
+
+#[cfg(all(feature = "std", unix))]
+impl PartialOrd<&OsStr> for UnixString {
+    fn partial_cmp(&self, other: &&OsStr) -> Option<Ordering> {
+        self.as_bytes().partial_cmp(other.as_bytes())
+    }
+}
+
+#[cfg(all(feature = "std", unix))]
+impl PartialOrd<UnixString> for &OsStr {
+    fn partial_cmp(&self, other: &UnixString) -> Option<Ordering> {
+        self.as_bytes().partial_cmp(other.as_bytes())
+    }
+}
+
+impl PartialOrd<&CStr> for UnixString {
+    fn partial_cmp(&self, other: &&CStr) -> Option<Ordering> {
+        self.as_bytes().partial_cmp(other.to_bytes())
+    }
+}
+
+impl PartialOrd<UnixString> for &CStr {
+    fn partial_cmp(&self, other: &UnixString) -> Option<Ordering> {
+        self.to_bytes().partial_cmp(other.as_bytes())
+    }
+}
+
+#[cfg(all(feature = "std", unix))]
+impl PartialOrd<&Path> for UnixString {
+    /// Compares to a `Path` using `Path`'s own ordering, which is done component by component.
+    ///
+    /// This keeps the comparison consistent with `PartialEq<&Path>` (under which `a//b` equals `a/b`),
+    /// but it's not necessarily equal to the byte-level ordering between `UnixString`s.
+    fn partial_cmp(&self, other: &&Path) -> Option<Ordering> {
+        self.as_path().partial_cmp(*other)
+    }
+}
+
+#[cfg(all(feature = "std", unix))]
+impl PartialOrd<UnixString> for &Path {
+    /// Compares to a `UnixString` using `Path`'s own ordering, which is done component by component.
+    ///
+    /// This keeps the comparison consistent with `PartialEq<UnixString>` (under which `a//b` equals `a/b`),
+    /// but it's not necessarily equal to the byte-level ordering between `UnixString`s.
+    fn partial_cmp(&self, other: &UnixString) -> Option<Ordering> {
+        (*self).partial_cmp(other.as_path())
+    }
+}
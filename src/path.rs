@@ -0,0 +1,444 @@
+//! Lexical path manipulation done directly over the bytes of a `UnixString`.
+//!
+//! None of these methods touch the filesystem and all of them work on non-UTF-8 paths.
+
+use alloc::vec::Vec;
+use core::ops::Range;
+#[cfg(all(feature = "std", unix))]
+use std::{ffi::OsStr, os::unix::prelude::OsStrExt};
+
+use crate::{memchr::memrchr, unix_string::strip_nul_terminator, Result, UnixString};
+
+const SEPARATOR: u8 = b'/';
+
+// Removes all trailing separators from the given bytes
+fn trim_trailing_separators(bytes: &[u8]) -> &[u8] {
+    let len = bytes
+        .iter()
+        .rposition(|&byte| byte != SEPARATOR)
+        .map_or(0, |pos| pos + 1);
+
+    &bytes[..len]
+}
+
+// Splits a file name into its stem and extension, following the rules of `Path::file_stem` and `Path::extension`
+fn split_stem_and_extension(file_name: &[u8]) -> (&[u8], Option<&[u8]>) {
+    match memrchr(b'.', file_name) {
+        Some(0) | None => (file_name, None),
+        Some(dot_pos) => (&file_name[..dot_pos], Some(&file_name[dot_pos + 1..])),
+    }
+}
+
+// Returns the range of the final component of the given path, ignoring trailing separators.
+// Returns `None` if the path is empty or made up only of separators (i.e. the root).
+fn file_name_range(bytes: &[u8]) -> Option<Range<usize>> {
+    let end = trim_trailing_separators(bytes).len();
+    if end == 0 {
+        return None;
+    }
+
+    let start = memrchr(SEPARATOR, &bytes[..end]).map_or(0, |pos| pos + 1);
+
+    Some(start..end)
+}
+
+// Splits the given path into its parent and its final component, ignoring trailing separators.
+fn split_parent_and_file_name(bytes: &[u8]) -> Option<(&[u8], &[u8])> {
+    let range = file_name_range(bytes)?;
+
+    let parent = match trim_trailing_separators(&bytes[..range.start]) {
+        // No separator at all before the file name
+        _ if range.start == 0 => &bytes[..0],
+        // The only separators before the file name were the ones of the root
+        b"" => &bytes[..1],
+        parent => parent,
+    };
+
+    Some((parent, &bytes[range]))
+}
+
+impl UnixString {
+    /// Returns the bytes of the parent directory of this path, if any.
+    ///
+    /// Works similarly to [`Path::parent`](std::path::Path::parent), but over the raw bytes of the `UnixString`:
+    /// trailing separators are ignored, `None` is returned for the root (`/`) or an empty string and a relative path
+    /// with a single component (e.g. `file`) has an empty parent.
+    ///
+    /// Just like in `Path`, a trailing `.` is not a component of its own: the parent of `dir/.` is the parent of `dir`,
+    /// i.e. an empty path, and `/.` has no parent. On the other hand, `..` is treated as a regular name.
+    ///
+    /// ```rust
+    /// use unixstring::UnixString;
+    ///
+    /// let unx = UnixString::from_string("/home/user/file.txt".into()).unwrap();
+    /// assert_eq!(unx.parent(), Some(&b"/home/user"[..]));
+    ///
+    /// let root = UnixString::from_string("/".into()).unwrap();
+    /// assert_eq!(root.parent(), None);
+    ///
+    /// let relative = UnixString::from_string("file".into()).unwrap();
+    /// assert_eq!(relative.parent(), Some(&b""[..]));
+    ///
+    /// let cur_dir = UnixString::from_string("/home/user/.".into()).unwrap();
+    /// assert_eq!(cur_dir.parent(), Some(&b"/home"[..]));
+    /// ```
+    pub fn parent(&self) -> Option<&[u8]> {
+        let (mut parent, mut file_name) = split_parent_and_file_name(self.as_bytes())?;
+
+        // Skip trailing `.` components, unless it's the leading `.` of a relative path (e.g. `.` or `./.`)
+        while file_name == b"." && !parent.is_empty() {
+            let (grandparent, parent_file_name) = split_parent_and_file_name(parent)?;
+            parent = grandparent;
+            file_name = parent_file_name;
+        }
+
+        Some(parent)
+    }
+
+    /// Returns the bytes of the final component of this path, if any.
+    ///
+    /// Works similarly to [`Path::file_name`](std::path::Path::file_name), but over the raw bytes of the `UnixString`:
+    /// trailing separators are ignored and `None` is returned for the root (`/`), an empty string or if the final
+    /// component is `.` or `..`.
+    ///
+    /// Unlike `Path`, which normalizes a trailing `.` away and so returns `a` for `a/.`, a final `.` component is never
+    /// skipped: `a/.` has no file name.
+    ///
+    /// ```rust
+    /// use unixstring::UnixString;
+    ///
+    /// let unx = UnixString::from_string("/home/user/file.txt".into()).unwrap();
+    /// assert_eq!(unx.file_name(), Some(&b"file.txt"[..]));
+    ///
+    /// let root = UnixString::from_string("/".into()).unwrap();
+    /// assert_eq!(root.file_name(), None);
+    ///
+    /// let relative = UnixString::from_string("file".into()).unwrap();
+    /// assert_eq!(relative.file_name(), Some(&b"file"[..]));
+    ///
+    /// let cur_dir = UnixString::from_string("dir/.".into()).unwrap();
+    /// assert_eq!(cur_dir.file_name(), None);
+    /// ```
+    pub fn file_name(&self) -> Option<&[u8]> {
+        match split_parent_and_file_name(self.as_bytes())? {
+            (_parent, b".") | (_parent, b"..") => None,
+            (_parent, file_name) => Some(file_name),
+        }
+    }
+
+    /// Returns the stem and the extension of the final component of this path, splitting it only once.
+    ///
+    /// Works similarly to calling both [`Path::file_stem`](std::path::Path::file_stem) and
+    /// [`Path::extension`](std::path::Path::extension), but over the raw bytes of the `UnixString`:
+    ///
+    /// * If there's no [file name](UnixString::file_name), both are `None`.
+    /// * The extension is what follows the last `.` of the file name, unless that `.` is the file name's first byte.
+    ///   Therefore `archive.tar.gz` has `archive.tar` as its stem and a dotfile such as `.bashrc` has no extension at all.
+    ///
+    /// ```rust
+    /// use unixstring::UnixString;
+    ///
+    /// let unx = UnixString::from_string("/tmp/archive.tar.gz".into()).unwrap();
+    /// assert_eq!(unx.file_stem_and_extension(), (Some(&b"archive.tar"[..]), Some(&b"gz"[..])));
+    ///
+    /// let dotfile = UnixString::from_string("/home/user/.bashrc".into()).unwrap();
+    /// assert_eq!(dotfile.file_stem_and_extension(), (Some(&b".bashrc"[..]), None));
+    /// ```
+    pub fn file_stem_and_extension(&self) -> (Option<&[u8]>, Option<&[u8]>) {
+        match self.file_name() {
+            Some(file_name) => {
+                let (stem, extension) = split_stem_and_extension(file_name);
+                (Some(stem), extension)
+            }
+            None => (None, None),
+        }
+    }
+
+    /// Truncates this path to its [`parent`](UnixString::parent), removing its final component in place.
+    ///
+    /// Works similarly to [`PathBuf::pop`](std::path::PathBuf::pop): returns `false` and does nothing if
+    /// there is no parent, i.e. if the path is the root (`/`) or empty. Popping a relative path with a
+    /// single component (e.g. `file`) leaves it empty. A trailing `.` is removed along with the component
+    /// preceding it, so popping `dir/.` leaves an empty path.
+    ///
+    /// ```rust
+    /// use unixstring::UnixString;
+    ///
+    /// let mut unx = UnixString::from_string("/home/user".into()).unwrap();
+    ///
+    /// assert!(unx.pop_path_component());
+    /// assert_eq!(unx.as_bytes(), b"/home");
+    ///
+    /// assert!(unx.pop_path_component());
+    /// assert_eq!(unx.as_bytes(), b"/");
+    ///
+    /// assert!(!unx.pop_path_component());
+    /// assert_eq!(unx.as_bytes(), b"/");
+    /// ```
+    pub fn pop_path_component(&mut self) -> bool {
+        match self.parent().map(<[u8]>::len) {
+            Some(len) => {
+                self.inner.truncate(len);
+                self.inner.push(0);
+                true
+            }
+            None => false,
+        }
+    }
+
+    /// Checks if this path is absolute, i.e. if it starts with `/`.
+    ///
+    /// ```rust
+    /// use unixstring::UnixString;
+    ///
+    /// assert!(UnixString::from_string("/etc/passwd".into()).unwrap().is_absolute());
+    /// assert!(!UnixString::from_string("etc/passwd".into()).unwrap().is_absolute());
+    /// ```
+    pub fn is_absolute(&self) -> bool {
+        self.as_bytes().first() == Some(&SEPARATOR)
+    }
+
+    /// Checks if this path is relative, i.e. if it doesn't start with `/`.
+    ///
+    /// An empty path is relative.
+    ///
+    /// ```rust
+    /// use unixstring::UnixString;
+    ///
+    /// assert!(UnixString::from_string("etc/passwd".into()).unwrap().is_relative());
+    /// assert!(UnixString::new().is_relative());
+    /// ```
+    pub fn is_relative(&self) -> bool {
+        !self.is_absolute()
+    }
+
+    /// Returns an iterator over the components of this path.
+    ///
+    /// Works similarly to [`Path::components`](std::path::Path::components), but yields raw bytes:
+    ///
+    /// * Repeated separators are collapsed, so `a//b` and `a/b` have the same components.
+    /// * The leading `/` of an absolute path is not yielded.
+    /// * A trailing separator is ignored.
+    /// * `.` components are skipped, except when at the start of a relative path.
+    /// * `..` components are always yielded, since resolving them lexically may not be correct in the presence of symlinks.
+    ///
+    /// ```rust
+    /// use unixstring::UnixString;
+    /// # use unixstring::Result;
+    /// # fn main() -> Result<()> {
+    /// let unx = UnixString::from_string("/usr//lib/./../bin/".into())?;
+    ///
+    /// let components: Vec<&[u8]> = unx.components().collect();
+    ///
+    /// assert_eq!(components, [&b"usr"[..], b"lib", b"..", b"bin"]);
+    /// # Ok(()) }
+    /// ```
+    pub fn components(&self) -> impl Iterator<Item = &[u8]> + '_ {
+        let bytes = self.as_bytes();
+
+        let leading_cur_dir = match bytes {
+            [b'.'] | [b'.', SEPARATOR, ..] => Some(&bytes[..1]),
+            _ => None,
+        };
+
+        leading_cur_dir.into_iter().chain(
+            bytes
+                .split(|&byte| byte == SEPARATOR)
+                .filter(|component| !component.is_empty() && component != b"."),
+        )
+    }
+
+    /// Returns the shortest path equivalent to this one through purely lexical processing, like Go's `path.Clean`.
+    ///
+    /// The filesystem is never accessed, so symbolic links are not resolved. For that, see [`std::fs::canonicalize`].
+    /// The following rules are applied:
+    ///
+    /// * Repeated separators are replaced by a single one and trailing separators are removed.
+    /// * `.` components are removed.
+    /// * A `..` component is removed along with the non-`..` component preceding it.
+    /// * A `..` component at the start of an absolute path is removed, since the parent of `/` is `/` itself.
+    /// * A `..` component at the start of a relative path is kept, as it can't be resolved lexically.
+    /// * If nothing is left, the result is `.` for relative paths and `/` for absolute ones.
+    ///
+    /// ```rust
+    /// use unixstring::UnixString;
+    /// # use unixstring::Result;
+    /// # fn main() -> Result<()> {
+    /// let unx = UnixString::from_string("/a/./b/../c".into())?;
+    /// assert_eq!(unx.clean().to_str()?, "/a/c");
+    ///
+    /// let unx = UnixString::from_string("a/../../b/".into())?;
+    /// assert_eq!(unx.clean().to_str()?, "../b");
+    /// # Ok(()) }
+    /// ```
+    pub fn clean(&self) -> UnixString {
+        let bytes = self.as_bytes();
+        let rooted = self.is_absolute();
+
+        let mut components: Vec<&[u8]> = Vec::new();
+        for component in bytes.split(|&byte| byte == SEPARATOR) {
+            match component {
+                b"" | b"." => {}
+                b".." => match components.last() {
+                    Some(&last) if last != b".." => {
+                        components.pop();
+                    }
+                    _ if rooted => {}
+                    _ => components.push(component),
+                },
+                _ => components.push(component),
+            }
+        }
+
+        // The cleaned path is never longer than the original one, except when it becomes `.`
+        let mut inner = Vec::with_capacity(bytes.len() + 2);
+        if rooted {
+            inner.push(SEPARATOR);
+        }
+        for (idx, component) in components.iter().enumerate() {
+            if idx > 0 {
+                inner.push(SEPARATOR);
+            }
+            inner.extend_from_slice(component);
+        }
+        if inner.is_empty() {
+            inner.push(b'.');
+        }
+        inner.push(b'\0');
+
+        UnixString { inner }
+    }
+
+    /// Replaces the extension of the final component of this path with `extension`, appending it if there's none.
+    ///
+    /// Works similarly to [`PathBuf::set_extension`](std::path::PathBuf::set_extension), but over the raw bytes of the `UnixString`:
+    ///
+    /// * If there's no [file name](UnixString::file_name) (e.g. `/`, an empty path or a path ending in `.` or `..`), nothing is done
+    ///   and `false` is returned.
+    /// * The extension is what follows the last `.` of the file name, unless that `.` is the file name's first byte.
+    ///   Therefore `archive.tar.gz` has `gz` as its extension and a dotfile such as `.bashrc` has no extension at all.
+    /// * If `extension` is empty, the current extension is removed along with its dot.
+    /// * Trailing separators after the file name are removed.
+    ///
+    /// Fails with [`Error::InteriorNulByte`](crate::Error::InteriorNulByte) if `extension` contains an interior zero byte, in which case `self` is left unchanged.
+    ///
+    /// ```rust
+    /// use unixstring::UnixString;
+    /// # use unixstring::Result;
+    /// # fn main() -> Result<()> {
+    /// let mut unx = UnixString::from_string("/tmp/archive.tar.gz".into())?;
+    ///
+    /// assert!(unx.set_extension("xz")?);
+    /// assert_eq!(unx.to_str()?, "/tmp/archive.tar.xz");
+    /// # Ok(()) }
+    /// ```
+    pub fn set_extension(&mut self, extension: impl AsRef<[u8]>) -> Result<bool> {
+        let extension = strip_nul_terminator(extension.as_ref())?;
+
+        let range = match file_name_range(self.as_bytes()) {
+            Some(range) if !matches!(&self.as_bytes()[range.clone()], b"." | b"..") => range,
+            _ => return Ok(false),
+        };
+
+        let (stem, _extension) = split_stem_and_extension(&self.as_bytes()[range.clone()]);
+        let stem_len = stem.len();
+
+        self.inner.truncate(range.start + stem_len);
+        if !extension.is_empty() {
+            self.inner.push(b'.');
+            self.inner.extend_from_slice(extension);
+        }
+        self.inner.push(0);
+
+        Ok(true)
+    }
+
+    /// Creates a new `UnixString` like `self` but with the given extension.
+    ///
+    /// See [`UnixString::set_extension`](UnixString::set_extension) for the rules on how the extension is replaced.
+    ///
+    /// ```rust
+    /// use unixstring::UnixString;
+    /// # use unixstring::Result;
+    /// # fn main() -> Result<()> {
+    /// let unx = UnixString::from_string("/home/user/data.tmp".into())?;
+    ///
+    /// assert_eq!(unx.with_extension("final")?.to_str()?, "/home/user/data.final");
+    /// assert_eq!(unx.with_extension("")?.to_str()?, "/home/user/data");
+    /// # Ok(()) }
+    /// ```
+    pub fn with_extension(&self, extension: impl AsRef<[u8]>) -> Result<UnixString> {
+        let mut unx = self.clone();
+        unx.set_extension(extension)?;
+        Ok(unx)
+    }
+
+    /// Extends this path with `component`, adding a separator between them if needed.
+    ///
+    /// Works similarly to [`PathBuf::push`](std::path::PathBuf::push):
+    ///
+    /// * If `component` is absolute, it replaces the current path.
+    /// * Otherwise, `component` is appended after a `/`, which is only added if the path is not empty and doesn't already end with one.
+    ///
+    /// Unlike [`UnixString::push`](UnixString::push), which concatenates the bytes as they are.
+    ///
+    /// Fails with [`Error::InteriorNulByte`](crate::Error::InteriorNulByte) if `component` contains an interior zero byte, in which case `self` is left unchanged.
+    ///
+    /// ```rust
+    /// use unixstring::UnixString;
+    /// # use unixstring::Result;
+    /// # fn main() -> Result<()> {
+    /// let mut unx = UnixString::from_string("/home".into())?;
+    ///
+    /// unx.push_path("user")?;
+    /// assert_eq!(unx.to_str()?, "/home/user");
+    ///
+    /// unx.push_path("/etc")?;
+    /// assert_eq!(unx.to_str()?, "/etc");
+    /// # Ok(()) }
+    /// ```
+    #[cfg(all(feature = "std", unix))]
+    pub fn push_path(&mut self, component: impl AsRef<OsStr>) -> Result<()> {
+        let component = strip_nul_terminator(component.as_ref().as_bytes())?;
+
+        if component.first() == Some(&SEPARATOR) {
+            self.clear();
+        } else if !self.is_empty() && self.as_bytes().last() != Some(&SEPARATOR) {
+            self.extend_slice(&[SEPARATOR]);
+        }
+
+        self.extend_slice(component);
+        Ok(())
+    }
+
+    /// Creates a `UnixString` by joining all of the given components, just like calling
+    /// [`UnixString::push_path`](UnixString::push_path) with each of them on an empty `UnixString`.
+    ///
+    /// Therefore a separator is added between components when needed and an absolute component replaces everything before it.
+    /// The components are appended as the iterator yields them, without collecting them first, and the buffer grows just like a `Vec`'s.
+    ///
+    /// ```rust
+    /// use unixstring::UnixString;
+    /// # use unixstring::Result;
+    /// # fn main() -> Result<()> {
+    /// let unx = UnixString::join_all(["/home", "user", "file"])?;
+    /// assert_eq!(unx.to_str()?, "/home/user/file");
+    ///
+    /// let unx = UnixString::join_all(["/home", "user", "/etc", "passwd"])?;
+    /// assert_eq!(unx.to_str()?, "/etc/passwd");
+    /// # Ok(()) }
+    /// ```
+    #[cfg(all(feature = "std", unix))]
+    pub fn join_all<I>(components: I) -> Result<UnixString>
+    where
+        I: IntoIterator,
+        I::Item: AsRef<OsStr>,
+    {
+        let mut unx = UnixString::new();
+        for component in components {
+            unx.push_path(component)?;
+        }
+
+        Ok(unx)
+    }
+}
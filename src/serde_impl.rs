@@ -0,0 +1,67 @@
+use alloc::{string::String, vec::Vec};
+use core::fmt;
+
+use serde::{
+    de::{self, SeqAccess, Visitor},
+    Deserialize, Deserializer, Serialize, Serializer,
+};
+
+use crate::UnixString;
+
+// Upper bound for the capacity preallocated from a sequence's size hint, just like serde's own `size_hint::cautious`
+const MAX_PREALLOCATED_BYTES: usize = 4096;
+
+impl Serialize for UnixString {
+    /// Serializes the bytes of this `UnixString`, without its nul terminator, as a byte array.
+    ///
+    /// Bytes are used instead of a string so that non-UTF-8 content survives a round-trip.
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.serialize_bytes(self.as_bytes())
+    }
+}
+
+impl<'de> Deserialize<'de> for UnixString {
+    /// Deserializes a `UnixString` from either a byte array, a sequence of bytes or a string.
+    ///
+    /// Fails if the given data contains an interior zero byte.
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        deserializer.deserialize_byte_buf(UnixStringVisitor)
+    }
+}
+
+struct UnixStringVisitor;
+
+impl<'de> Visitor<'de> for UnixStringVisitor {
+    type Value = UnixString;
+
+    fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+        formatter.write_str("a byte string without interior zero bytes")
+    }
+
+    fn visit_byte_buf<E: de::Error>(self, bytes: Vec<u8>) -> Result<Self::Value, E> {
+        UnixString::from_bytes(bytes).map_err(E::custom)
+    }
+
+    fn visit_bytes<E: de::Error>(self, bytes: &[u8]) -> Result<Self::Value, E> {
+        self.visit_byte_buf(bytes.to_vec())
+    }
+
+    fn visit_string<E: de::Error>(self, string: String) -> Result<Self::Value, E> {
+        self.visit_byte_buf(string.into_bytes())
+    }
+
+    fn visit_str<E: de::Error>(self, string: &str) -> Result<Self::Value, E> {
+        self.visit_bytes(string.as_bytes())
+    }
+
+    fn visit_seq<A: SeqAccess<'de>>(self, mut seq: A) -> Result<Self::Value, A::Error> {
+        // The size hint comes from the input, so don't trust it with large preallocations
+        let capacity = seq.size_hint().unwrap_or(0).min(MAX_PREALLOCATED_BYTES);
+        let mut bytes = Vec::with_capacity(capacity.saturating_add(1));
+        while let Some(byte) = seq.next_element()? {
+            bytes.push(byte);
+        }
+
+        self.visit_byte_buf(bytes)
+    }
+}
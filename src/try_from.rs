@@ -1,10 +1,13 @@
-use std::convert::TryInto;
-use std::ffi::OsString;
-use std::{convert::TryFrom, path::PathBuf};
+use alloc::{string::String, vec, vec::Vec};
+use core::convert::TryFrom;
+#[cfg(all(feature = "std", unix))]
+use std::{convert::TryInto, ffi::OsString, path::PathBuf};
 
+use crate::unix_string::strip_nul_terminator;
 use crate::Result;
-use crate::UnixString;
+use crate::{Error, UnixString};
 
+#[cfg(all(feature = "std", unix))]
 impl TryFrom<PathBuf> for UnixString {
     type Error = crate::error::Error;
 
@@ -13,6 +16,7 @@ impl TryFrom<PathBuf> for UnixString {
     }
 }
 
+#[cfg(all(feature = "std", unix))]
 impl TryFrom<OsString> for UnixString {
     type Error = crate::error::Error;
 
@@ -38,3 +42,105 @@ impl TryFrom<Vec<u8>> for UnixString {
         Self::from_bytes(bytes)
     }
 }
+
+/// Creates a `UnixString` by copying the given bytes.
+///
+/// Just like [`UnixString::from_bytes`](UnixString::from_bytes), a zero byte is only accepted at the last position.
+///
+/// ```rust
+/// use std::convert::TryFrom;
+///
+/// use unixstring::UnixString;
+///
+/// assert_eq!(UnixString::try_from(&b"/usr/lib"[..]).unwrap().as_bytes(), b"/usr/lib");
+/// assert!(UnixString::try_from(&b"/usr\0/lib"[..]).is_err());
+/// ```
+impl TryFrom<&[u8]> for UnixString {
+    type Error = crate::error::Error;
+
+    fn try_from(bytes: &[u8]) -> Result<Self> {
+        let bytes = strip_nul_terminator(bytes)?;
+
+        let mut inner = Vec::with_capacity(bytes.len() + 1);
+        inner.extend_from_slice(bytes);
+        inner.push(b'\0');
+
+        Ok(Self { inner })
+    }
+}
+
+/// Creates a `UnixString` by copying the given string slice.
+///
+/// Just like [`UnixString::from_string`](UnixString::from_string), a zero byte is only accepted at the last position.
+///
+/// ```rust
+/// use std::convert::TryFrom;
+///
+/// use unixstring::UnixString;
+///
+/// assert_eq!(UnixString::try_from("/usr/lib").unwrap().to_str().unwrap(), "/usr/lib");
+/// ```
+impl TryFrom<&str> for UnixString {
+    type Error = crate::error::Error;
+
+    fn try_from(value: &str) -> Result<Self> {
+        Self::try_from(value.as_bytes())
+    }
+}
+
+/// Creates a `UnixString` holding the UTF-8 encoding of the given `char`.
+///
+/// Fails with [`Error::InteriorNulByte`] for `'\0'`, since it would otherwise result in an empty `UnixString`.
+///
+/// ```rust
+/// use std::convert::TryFrom;
+///
+/// use unixstring::UnixString;
+///
+/// assert_eq!(UnixString::try_from('é').unwrap().as_bytes(), "é".as_bytes());
+/// assert!(UnixString::try_from('\0').is_err());
+/// ```
+impl TryFrom<char> for UnixString {
+    type Error = crate::error::Error;
+
+    fn try_from(value: char) -> Result<Self> {
+        if value == '\0' {
+            return Err(Error::InteriorNulByte { position: 0 });
+        }
+
+        let mut buf = [0; 4];
+        let encoded = value.encode_utf8(&mut buf);
+
+        let mut inner = Vec::with_capacity(encoded.len() + 1);
+        inner.extend_from_slice(encoded.as_bytes());
+        inner.push(b'\0');
+
+        Ok(Self { inner })
+    }
+}
+
+/// Creates a `UnixString` holding the given byte.
+///
+/// Fails with [`Error::InteriorNulByte`] for a zero byte, since it would otherwise result in an empty `UnixString`.
+///
+/// ```rust
+/// use std::convert::TryFrom;
+///
+/// use unixstring::UnixString;
+///
+/// assert_eq!(UnixString::try_from(b'/').unwrap().as_bytes(), b"/");
+/// assert!(UnixString::try_from(0_u8).is_err());
+/// ```
+impl TryFrom<u8> for UnixString {
+    type Error = crate::error::Error;
+
+    fn try_from(value: u8) -> Result<Self> {
+        if value == 0 {
+            return Err(Error::InteriorNulByte { position: 0 });
+        }
+
+        Ok(Self {
+            inner: vec![value, b'\0'],
+        })
+    }
+}
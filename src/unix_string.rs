@@ -1,19 +1,33 @@
-use std::{
-    borrow::Cow,
+use alloc::{
+    borrow::{Cow, ToOwned},
+    boxed::Box,
+    ffi::CString,
+    rc::Rc,
+    string::String,
+    sync::Arc,
+    vec,
+    vec::Vec,
+};
+use core::{
     convert::TryInto,
-    ffi::{CStr, CString, OsStr, OsString},
+    ffi::CStr,
+    ops::{Bound, Range, RangeBounds},
+};
+#[cfg(all(feature = "std", unix))]
+use std::{
+    ffi::{OsStr, OsString},
     os::unix::prelude::OsStrExt,
     path::{Path, PathBuf},
 };
 
 use crate::error::{Error, Result};
-use crate::memchr::find_nul_byte;
+use crate::memchr::{find_nul_byte, memchr, memrchr};
 
-#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[derive(Clone, PartialEq, Eq, PartialOrd, Ord)]
 /// An FFI-friendly null-terminated byte string.
 #[non_exhaustive]
 pub struct UnixString {
-    inner: Vec<u8>,
+    pub(crate) inner: Vec<u8>,
 }
 
 impl Default for UnixString {
@@ -22,6 +36,76 @@ impl Default for UnixString {
     }
 }
 
+// Returns the given bytes without their nul terminator, if they have one.
+// Fails if the given bytes have a zero byte anywhere but at their end.
+pub(crate) fn strip_nul_terminator(bytes: &[u8]) -> Result<&[u8]> {
+    match find_nul_byte(bytes) {
+        Some(nul_pos) if nul_pos + 1 == bytes.len() => Ok(&bytes[..nul_pos]),
+        Some(nul_pos) => Err(Error::InteriorNulByte { position: nul_pos }),
+        None => Ok(bytes),
+    }
+}
+
+// Returns the index of the first occurrence of `needle` in `haystack`, if any. An empty `needle` matches at index 0.
+fn find_subslice(haystack: &[u8], needle: &[u8]) -> Option<usize> {
+    match needle {
+        [] => Some(0),
+        &[byte] => memchr(byte, haystack),
+        needle => haystack
+            .windows(needle.len())
+            .position(|window| window == needle),
+    }
+}
+
+// Splits off the longest valid UTF-8 prefix of `bytes`, returning it along with the length of the invalid
+// sequence that follows it, which is zero if all of `bytes` is valid UTF-8.
+fn split_valid_utf8(bytes: &[u8]) -> (&str, usize) {
+    match core::str::from_utf8(bytes) {
+        Ok(valid) => (valid, 0),
+        Err(err) => {
+            let valid_len = err.valid_up_to();
+            let invalid_len = err.error_len().unwrap_or(bytes.len() - valid_len);
+
+            // Safety: `from_utf8` checked that the first `valid_len` bytes are valid UTF-8
+            let valid = unsafe { core::str::from_utf8_unchecked(&bytes[..valid_len]) };
+            (valid, invalid_len)
+        }
+    }
+}
+
+// Truncates the given buffer to `len` and appends a nul terminator when dropped, including during unwinding
+struct TerminateOnDrop<'a> {
+    inner: &'a mut Vec<u8>,
+    len: usize,
+}
+
+impl Drop for TerminateOnDrop<'_> {
+    fn drop(&mut self) {
+        self.inner.truncate(self.len);
+        self.inner.push(0);
+    }
+}
+
+// Converts the given range bounds into a `Range`, checking that it lies within `0..=len`.
+fn resolve_range(range: impl RangeBounds<usize>, len: usize) -> Option<Range<usize>> {
+    let start = match range.start_bound() {
+        Bound::Included(&start) => start,
+        Bound::Excluded(&start) => start.checked_add(1)?,
+        Bound::Unbounded => 0,
+    };
+    let end = match range.end_bound() {
+        Bound::Included(&end) => end.checked_add(1)?,
+        Bound::Excluded(&end) => end,
+        Bound::Unbounded => len,
+    };
+
+    if start <= end && end <= len {
+        Some(start..end)
+    } else {
+        None
+    }
+}
+
 impl UnixString {
     /// Constructs a new, "empty" `UnixString`.
     ///
@@ -39,34 +123,50 @@ impl UnixString {
     /// This method is particularly useful to guarantee that a `UnixString` remains valid after being possibly modified through [`UnixString::as_mut_ptr`](UnixString::as_mut_ptr),
     /// or making sure that a `UnixString` created from [`UnixString::from_ptr`](UnixString::from_ptr) is correct.
     ///
+    /// Returns [`Error::MissingNulTerminator`] if the last byte is not zero and, otherwise, [`Error::InteriorNulByte`]
+    /// with the position of the first zero byte found before it.
+    ///
     /// ```rust
     /// use unixstring::UnixString;
     /// # use unixstring::Result;
     /// # fn main() -> Result<()> {
     ///
     /// let mut unix_string = UnixString::new();
-    /// unix_string.push("hello")?;
-    /// unix_string.push("world")?;
+    /// unix_string.push_bytes(b"hello")?;
+    /// unix_string.push_bytes(b"world")?;
     ///
     /// assert!(unix_string.validate().is_ok());
     ///
     /// # Ok(()) }
     /// ```
     pub fn validate(&self) -> Result<()> {
-        let bytes = &*self.inner;
-        match find_nul_byte(bytes) {
-            Some(nul_pos) if nul_pos + 1 == bytes.len() => Ok(()),
-            Some(_nul_pos) => Err(Error::InteriorNulByte),
-            None => Err(Error::MissingNulTerminator),
+        // The terminator is checked first, in constant time, so that only the content is scanned afterwards
+        let content = match self.inner.split_last() {
+            Some((0, content)) => content,
+            _ => return Err(Error::MissingNulTerminator),
+        };
+
+        match find_nul_byte(content) {
+            Some(nul_pos) => Err(Error::InteriorNulByte { position: nul_pos }),
+            None => Ok(()),
         }
     }
 
-    // Removes the existing nul terminator and then extends `self` with the given bytes.
-    // Assumes that the given bytes have a nul terminator
-    fn extend_slice(&mut self, slice: &[u8]) {
-        let removed = self.inner.remove(self.inner.len() - 1);
-        debug_assert!(removed == 0);
-        self.inner.extend_from_slice(slice);
+    // Appends the given bytes, which must not contain any zero byte, to `self`.
+    // The first byte overwrites the existing nul terminator in place and a new
+    // terminator is pushed at the end, so the buffer is never shifted.
+    pub(crate) fn extend_slice(&mut self, slice: &[u8]) {
+        if let Some((&first, rest)) = slice.split_first() {
+            self.inner.reserve(slice.len());
+            let terminator = self
+                .inner
+                .last_mut()
+                .expect("UnixString always has a nul terminator");
+            debug_assert!(*terminator == 0);
+            *terminator = first;
+            self.inner.extend_from_slice(rest);
+            self.inner.push(b'\0');
+        }
     }
 
     /// Extends the `UnixString` with anything that implements [`AsRef`](std::convert::AsRef)<[`OsStr`](std::ffi::OsStr)>.
@@ -87,10 +187,71 @@ impl UnixString {
     /// assert_eq!(unix_string.to_str()?, "/home/user");
     /// # Ok(()) }
     ///
+    #[cfg(all(feature = "std", unix))]
     pub fn push(&mut self, value: impl AsRef<OsStr>) -> Result<()> {
         self.push_bytes(value.as_ref().as_bytes())
     }
 
+    /// Extends the `UnixString` with anything that implements [`AsRef`](std::convert::AsRef)<[`OsStr`](std::ffi::OsStr)>,
+    /// such as a [`Path`] or an [`OsString`], reserving the space needed for it before copying.
+    ///
+    /// Works just like [`UnixString::push`](UnixString::push), with a single reservation up front so that large
+    /// appends never reallocate more than once.
+    ///
+    /// ```rust
+    /// # use unixstring::Result;
+    /// use std::path::PathBuf;
+    ///
+    /// use unixstring::UnixString;
+    /// # fn main() -> Result<()> {
+    /// let mut unix_string = UnixString::new();
+    ///
+    /// unix_string.extend_from_os_str(PathBuf::from("/home/user"))?;
+    ///
+    /// assert_eq!(unix_string.to_str()?, "/home/user");
+    /// # Ok(()) }
+    /// ```
+    #[cfg(all(feature = "std", unix))]
+    pub fn extend_from_os_str(&mut self, value: impl AsRef<OsStr>) -> Result<()> {
+        let bytes = strip_nul_terminator(value.as_ref().as_bytes())?;
+        self.reserve(bytes.len());
+        self.extend_slice(bytes);
+        Ok(())
+    }
+
+    /// Extends the `UnixString` with each of the items yielded by the given iterator, in order.
+    ///
+    /// Every item is appended through [`UnixString::push`](UnixString::push), so no separator is added between them.
+    ///
+    /// If an item contains an interior zero byte, this method stops and returns an error. In that case, `self` holds
+    /// every item pushed before the offending one and nothing from it or from the items after it.
+    ///
+    /// ```rust
+    /// # use unixstring::Result;
+    /// use unixstring::UnixString;
+    /// # fn main() -> Result<()> {
+    /// let mut unix_string = UnixString::new();
+    ///
+    /// unix_string.try_extend_os(["/home", "/user"])?;
+    /// assert_eq!(unix_string.to_str()?, "/home/user");
+    ///
+    /// assert!(unix_string.try_extend_os(["/.config", "/nv\0im", "/init.lua"]).is_err());
+    /// assert_eq!(unix_string.to_str()?, "/home/user/.config");
+    /// # Ok(()) }
+    /// ```
+    #[cfg(all(feature = "std", unix))]
+    pub fn try_extend_os<I>(&mut self, iter: I) -> Result<()>
+    where
+        I: IntoIterator,
+        I::Item: AsRef<OsStr>,
+    {
+        for item in iter {
+            self.push(item)?;
+        }
+
+        Ok(())
+    }
+
     /// Extends the `UnixString` with the given bytes.
     ///
     /// This method fails if the bytes contain an interior zero byte (a zero byte not at the buffer's final position)
@@ -109,21 +270,219 @@ impl UnixString {
     /// assert_eq!(unix_string.into_bytes(), abc);
     /// # Ok(()) }
     pub fn push_bytes(&mut self, bytes: &[u8]) -> Result<()> {
-        match find_nul_byte(bytes) {
-            Some(nul_pos) if nul_pos + 1 == bytes.len() => {
-                // The given bytes already have a nul terminator
-                self.extend_slice(bytes);
-                Ok(())
+        let bytes = strip_nul_terminator(bytes)?;
+        self.extend_slice(bytes);
+        Ok(())
+    }
+
+    /// Extends the `UnixString` with the given bytes, growing its capacity to exactly what's needed to hold them.
+    ///
+    /// Works just like [`UnixString::push_bytes`](UnixString::push_bytes), except that [`UnixString::push_bytes`]
+    /// may over-allocate so that repeated pushes take amortized constant time. This method is useful when the final size
+    /// of the `UnixString` is known, as it uses less memory at the cost of reallocating on every push that doesn't fit.
+    ///
+    /// Note that the allocator may still give the `UnixString` more space than requested.
+    ///
+    /// ```rust
+    /// # use unixstring::Result;
+    /// use unixstring::UnixString;
+    /// # fn main() -> Result<()> {
+    /// let mut unix_string = UnixString::new();
+    ///
+    /// unix_string.push_bytes_exact(b"/home/user")?;
+    ///
+    /// assert_eq!(unix_string.as_bytes(), b"/home/user");
+    /// assert_eq!(unix_string.capacity(), unix_string.len_with_nul());
+    /// # Ok(()) }
+    /// ```
+    pub fn push_bytes_exact(&mut self, bytes: &[u8]) -> Result<()> {
+        let bytes = strip_nul_terminator(bytes)?;
+        self.inner.reserve_exact(bytes.len());
+        self.extend_slice(bytes);
+        Ok(())
+    }
+
+    /// Extends the `UnixString` with all of the given slices, with no separator between them.
+    ///
+    /// This is equivalent to calling [`UnixString::push_bytes`](UnixString::push_bytes) with each slice, but the
+    /// total length is reserved once and the nul terminator is only written once, after the last slice.
+    /// Just like in [`UnixString::concat`](UnixString::concat), a slice may end with a zero byte, but a zero byte
+    /// anywhere else results in an error whose position refers to the concatenated slices.
+    ///
+    /// All slices are checked before anything is appended, so `self` is left unchanged on error.
+    ///
+    /// ```rust
+    /// use unixstring::UnixString;
+    /// # use unixstring::Result;
+    /// # fn main() -> Result<()> {
+    /// let mut unx = UnixString::from_string("/home".into())?;
+    ///
+    /// unx.extend_from_slices(&[b"/user", b"/.config", b"/nvim"])?;
+    /// assert_eq!(unx.to_str()?, "/home/user/.config/nvim");
+    ///
+    /// assert!(unx.extend_from_slices(&[b"/init", b".l\0ua"]).is_err());
+    /// # Ok(()) }
+    /// ```
+    pub fn extend_from_slices(&mut self, slices: &[&[u8]]) -> Result<()> {
+        let mut total_len = 0;
+        for slice in slices {
+            match strip_nul_terminator(slice) {
+                Ok(slice) => total_len += slice.len(),
+                Err(Error::InteriorNulByte { position }) => {
+                    return Err(Error::InteriorNulByte {
+                        position: total_len + position,
+                    })
+                }
+                Err(err) => return Err(err),
             }
-            Some(_nul_pos) => Err(Error::InteriorNulByte),
-            None => {
-                // There was no zero byte at all on the given bytes so we'll
-                // have to manually append the null terminator after appending.
-                self.extend_slice(bytes);
-                self.inner.extend(Some(b'\0'));
-                Ok(())
+        }
+
+        self.inner.reserve(total_len);
+        self.inner.pop();
+        for slice in slices {
+            let slice = slice.strip_suffix(b"\0").unwrap_or(slice);
+            self.inner.extend_from_slice(slice);
+        }
+        self.inner.push(0);
+
+        Ok(())
+    }
+
+    /// Moves the contents of `other` to the end of this `UnixString`.
+    ///
+    /// Since both `UnixString`s are known to be valid, this never fails and `other` is not re-validated.
+    ///
+    /// ```rust
+    /// use unixstring::UnixString;
+    ///
+    /// let mut home = UnixString::from_string("/home".into()).unwrap();
+    /// let user = UnixString::from_string("/user".into()).unwrap();
+    ///
+    /// home.append(user);
+    ///
+    /// assert_eq!(home.as_bytes_with_nul(), b"/home/user\0");
+    /// ```
+    pub fn append(&mut self, other: UnixString) {
+        if self.is_empty() && other.inner.capacity() > self.inner.capacity() {
+            // Reuse `other`'s buffer instead of copying into ours
+            *self = other;
+        } else {
+            self.extend_slice(other.as_bytes());
+        }
+    }
+
+    /// Extends the `UnixString` with the bytes yielded by the given iterator.
+    ///
+    /// Just like [`UnixString::push_bytes`](UnixString::push_bytes), a zero byte is only accepted as the very last byte yielded.
+    /// If an interior zero byte is found, iteration stops, [`Error::InteriorNulByte`] is returned and `self` is left unchanged.
+    ///
+    /// ```rust
+    /// use unixstring::UnixString;
+    /// # use unixstring::Result;
+    /// # fn main() -> Result<()> {
+    /// let mut unix_string = UnixString::new();
+    ///
+    /// unix_string.try_extend(b"/home".iter().copied())?;
+    /// assert_eq!(unix_string.as_bytes(), b"/home");
+    ///
+    /// // Fails: interior zero byte
+    /// assert!(unix_string.try_extend(b"/us\0r".iter().copied()).is_err());
+    /// assert_eq!(unix_string.as_bytes(), b"/home");
+    /// # Ok(()) }
+    /// ```
+    pub fn try_extend<I: IntoIterator<Item = u8>>(&mut self, iter: I) -> Result<()> {
+        let mut iter = iter.into_iter();
+        let (lower_bound, _) = iter.size_hint();
+        self.inner.reserve(lower_bound);
+
+        let original_len = self.inner.len();
+        let removed = self.inner.pop();
+        debug_assert!(removed == Some(0));
+
+        while let Some(byte) = iter.next() {
+            if byte == 0 {
+                if iter.next().is_none() {
+                    // The zero byte was the last one yielded, so it'll act as our terminator
+                    break;
+                }
+
+                // The offset of this zero byte within the bytes yielded by `iter`
+                let position = self.inner.len() - (original_len - 1);
+
+                // Restore `self` to how it was before this call
+                self.inner.truncate(original_len - 1);
+                self.inner.push(0);
+                return Err(Error::InteriorNulByte { position });
             }
+            self.inner.push(byte);
         }
+
+        self.inner.push(0);
+        Ok(())
+    }
+
+    /// Inserts the given bytes into this `UnixString` at the byte position `index`.
+    ///
+    /// Just like [`UnixString::push_bytes`](UnixString::push_bytes), this method fails if the bytes contain an interior zero byte,
+    /// in which case `self` is left unchanged.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `index > len`, where `len` is the length of this `UnixString` *without* its nul terminator.
+    ///
+    /// ```rust
+    /// use unixstring::UnixString;
+    /// # use unixstring::Result;
+    /// # fn main() -> Result<()> {
+    /// let mut unix_string = UnixString::from_string("/home/file".into())?;
+    ///
+    /// unix_string.insert_bytes(6, b"user/")?;
+    /// assert_eq!(unix_string.to_str()?, "/home/user/file");
+    ///
+    /// // Fails: interior zero byte
+    /// assert!(unix_string.insert_bytes(0, b"a\0b").is_err());
+    /// # Ok(()) }
+    /// ```
+    pub fn insert_bytes(&mut self, index: usize, bytes: &[u8]) -> Result<()> {
+        let len = self.len();
+        assert!(
+            index <= len,
+            "insertion index (is {}) should be <= len (is {})",
+            index,
+            len
+        );
+
+        let bytes = strip_nul_terminator(bytes)?;
+        self.inner.splice(index..index, bytes.iter().copied());
+
+        Ok(())
+    }
+
+    /// Inserts the given bytes at the start of this `UnixString`, shifting its current contents to the right.
+    ///
+    /// This is the counterpart of [`UnixString::push_bytes`](UnixString::push_bytes) and works just like
+    /// [`insert_bytes(0, bytes)`](UnixString::insert_bytes). The space needed for `bytes` is reserved once,
+    /// upfront, so prepending a long prefix reallocates at most once.
+    ///
+    /// This method fails if the bytes contain an interior zero byte, in which case `self` is left unchanged.
+    ///
+    /// ```rust
+    /// use unixstring::UnixString;
+    /// # use unixstring::Result;
+    /// # fn main() -> Result<()> {
+    /// let mut unix_string = UnixString::from_string("user/file".into())?;
+    ///
+    /// unix_string.prepend("/home/")?;
+    /// assert_eq!(unix_string.to_str()?, "/home/user/file");
+    /// # Ok(()) }
+    /// ```
+    pub fn prepend(&mut self, bytes: impl AsRef<[u8]>) -> Result<()> {
+        let bytes = strip_nul_terminator(bytes.as_ref())?;
+
+        self.inner.reserve(bytes.len());
+        self.inner.splice(..0, bytes.iter().copied());
+
+        Ok(())
     }
 
     /// Creates a [`UnixString`](UnixString) given a `Vec` of bytes.
@@ -148,7 +507,7 @@ impl UnixString {
     pub fn from_bytes(bytes: Vec<u8>) -> Result<Self> {
         match find_nul_byte(&bytes) {
             Some(nul_pos) if nul_pos + 1 == bytes.len() => Ok(Self { inner: bytes }),
-            Some(_nul_pos) => Err(Error::InteriorNulByte),
+            Some(nul_pos) => Err(Error::InteriorNulByte { position: nul_pos }),
             None => {
                 let mut bytes = bytes;
                 bytes.extend(Some(b'\0'));
@@ -157,334 +516,1767 @@ impl UnixString {
         }
     }
 
-    /// Constructs a new, empty `UnixString` with the specified capacity.
-    ///
-    /// The `UnixString`'s inner vector will be able to hold exactly `capacity` elements without
-    /// reallocating.
+    /// Creates a [`UnixString`](UnixString) from the bytes yielded by the given iterator.
     ///
-    /// This function will always allocate enough to fit the null terminator byte, even if the given capacity is 0.
+    /// Just like [`UnixString::from_bytes`](UnixString::from_bytes), a zero byte is only accepted as the very last byte yielded.
+    /// This is the fallible counterpart of collecting an iterator into a `UnixString`, which panics on an interior zero byte.
     ///
+    /// ```rust
+    /// use unixstring::UnixString;
     ///
-    /// # Panics
+    /// let unx = UnixString::try_from_bytes_iter(b"/usr/lib".iter().copied()).unwrap();
+    /// assert_eq!(unx.as_bytes_with_nul(), b"/usr/lib\0");
     ///
-    /// Panics if the new capacity exceeds `isize::MAX - 1` bytes.
-    pub fn with_capacity(capacity: usize) -> Self {
-        let mut inner = Vec::with_capacity(capacity + 1);
-        inner.push(0);
+    /// assert!(UnixString::try_from_bytes_iter(b"/usr\0/lib".iter().copied()).is_err());
+    /// ```
+    pub fn try_from_bytes_iter<I: IntoIterator<Item = u8>>(iter: I) -> Result<Self> {
+        let mut unx = Self::new();
+        unx.try_extend(iter)?;
 
-        Self { inner }
+        Ok(unx)
     }
 
-    /// Clones a raw C string into an `UnixString`.
+    /// Creates a [`UnixString`](UnixString) given a `Vec` of bytes, reserving capacity for at least `additional` more bytes.
     ///
-    /// The total size of the raw C string must be smaller than `isize::MAX` **bytes**
-    /// in memory due to calling the `slice::from_raw_parts` function.
+    /// Validation works just like [`UnixString::from_bytes`](UnixString::from_bytes): a trailing nul terminator is accepted
+    /// (and not doubled up) while an interior zero byte results in an error.
     ///
-    /// # Safety
+    /// The terminator and the extra capacity are reserved at once, avoiding the reallocation that calling
+    /// [`UnixString::reserve`](UnixString::reserve) after construction could cause.
     ///
-    /// This method is unsafe for a number of reasons:
+    /// # Panics
     ///
-    /// * There is no guarantee to the validity of `ptr`.
-    /// * There is no guarantee that the memory pointed to by `ptr` contains a
-    ///   valid nul terminator byte at the end of the string.
-    /// * It is not guaranteed that the memory pointed by `ptr` won't change
-    ///   before the `UnixString` has been constructed.
+    /// Panics if the new capacity exceeds `isize::MAX` bytes.
     ///
-    /// See [`CStr::from_ptr`](std::ffi::CStr::from_ptr) for more info.
-    pub unsafe fn from_ptr(ptr: *const libc::c_char) -> Self {
-        CStr::from_ptr(ptr).to_owned().into()
+    /// ```rust
+    /// use unixstring::UnixString;
+    ///
+    /// let unx = UnixString::from_bytes_with_capacity(b"/home".to_vec(), 64).unwrap();
+    ///
+    /// assert_eq!(unx.as_bytes_with_nul(), b"/home\0");
+    /// assert!(unx.capacity() >= unx.len_with_nul() + 64);
+    /// ```
+    pub fn from_bytes_with_capacity(bytes: Vec<u8>, additional: usize) -> Result<Self> {
+        let mut bytes = bytes;
+        match find_nul_byte(&bytes) {
+            Some(nul_pos) if nul_pos + 1 == bytes.len() => bytes.reserve(additional),
+            Some(nul_pos) => return Err(Error::InteriorNulByte { position: nul_pos }),
+            None => {
+                // `usize::MAX + 1` bytes can't be reserved anyway, so reserving `usize::MAX` panics just the same
+                bytes.reserve(additional.saturating_add(1));
+                bytes.push(b'\0');
+            }
+        }
+
+        Ok(Self { inner: bytes })
     }
 
-    /// Returns an inner pointer to the data this `UnixString` contains.
-    ///
-    /// The returned pointer will be valid for as long as the given `UnixString` is, and points
-    /// to a null-terminated contiguous region of memory.
+    /// Creates a [`UnixString`](UnixString) given a `Vec` of bytes, truncating it at its first zero byte.
     ///
-    /// *Note*: The returned pointer is read-only and writing to it in any way causes undefined behavior.
+    /// This matches the semantics of C strings, which end at their first nul byte: everything after it is discarded.
+    /// Unlike [`UnixString::from_bytes`](UnixString::from_bytes), this method never fails.
     ///
-    /// You must ensure that the underlying memory is not
-    /// freed too early. If the `UnixString` is deallocated then the pointer becomes dangling.
+    /// ```rust
+    /// use unixstring::UnixString;
     ///
-    /// See [`CStr::as_ptr`](std::ffi::CStr::as_ptr) for more info.
+    /// let unx = UnixString::from_bytes_lossy(b"abc\0def".to_vec());
     ///
-    pub fn as_ptr(&self) -> *const libc::c_char {
-        self.as_c_str().as_ptr()
-    }
+    /// assert_eq!(unx.as_bytes_with_nul(), b"abc\0");
+    /// ```
+    pub fn from_bytes_lossy(bytes: Vec<u8>) -> Self {
+        let mut bytes = bytes;
+        match find_nul_byte(&bytes) {
+            Some(nul_pos) => bytes.truncate(nul_pos + 1),
+            None => bytes.push(b'\0'),
+        }
 
-    fn inner_without_nul_terminator(&self) -> &[u8] {
-        &self.inner[0..self.inner.len() - 1]
+        Self { inner: bytes }
     }
 
-    /// Converts the `UnixString` to an [`OsStr`] slice. This always succeeds and is zero cost. The terminating nul byte will not be included in the `OsStr` slice.
-    /// ```rust
-    /// use std::{convert::TryFrom, path::PathBuf};
-
-    /// use unixstring::UnixString;
+    /// Creates a [`UnixString`](UnixString) by concatenating all of the given parts, with no separator between them.
     ///
-    /// let logs = PathBuf::from("/var/log/journal");
-    /// let unix_string = UnixString::try_from(logs.clone()).unwrap();
+    /// This is equivalent to calling [`UnixString::push_bytes`](UnixString::push_bytes) with each part on an
+    /// empty `UnixString`, but the total length is reserved up front. Therefore a part may end with a zero byte,
+    /// but a zero byte anywhere else results in an error whose position refers to the concatenated content.
     ///
-    /// assert_eq!(
-    ///     logs.as_os_str(),
-    ///     unix_string.as_os_str()
-    /// )
+    /// ```rust
+    /// use unixstring::UnixString;
+    /// # use unixstring::Result;
+    /// # fn main() -> Result<()> {
+    /// let unx = UnixString::concat(&["/home", "/user", "/.bashrc"])?;
     ///
+    /// assert_eq!(unx.to_str()?, "/home/user/.bashrc");
+    /// # Ok(()) }
     /// ```
-    pub fn as_os_str(&self) -> &OsStr {
-        OsStr::from_bytes(self.inner_without_nul_terminator())
+    pub fn concat(parts: &[impl AsRef<[u8]>]) -> Result<UnixString> {
+        let total_len: usize = parts.iter().map(|part| part.as_ref().len()).sum();
+        let mut inner = Vec::with_capacity(total_len + 1);
+
+        for part in parts {
+            let part = match strip_nul_terminator(part.as_ref()) {
+                Ok(part) => part,
+                Err(Error::InteriorNulByte { position }) => {
+                    return Err(Error::InteriorNulByte {
+                        position: inner.len() + position,
+                    })
+                }
+                Err(err) => return Err(err),
+            };
+            inner.extend_from_slice(part);
+        }
+        inner.push(b'\0');
+
+        Ok(Self { inner })
     }
 
-    /// Converts the `UnixString` to a [`Path`] slice. This always succeeds and is zero cost.
-    /// The terminating nul byte will not be included in the `Path` slice.
-    /// ```rust
-    /// use std::{convert::TryFrom, path::PathBuf};
+    /// Creates a [`UnixString`](UnixString) given a `Vec` of bytes that must already be nul-terminated.
     ///
+    /// Unlike [`UnixString::from_bytes`](UnixString::from_bytes), no nul terminator is appended: this method returns
+    /// [`Error::MissingNulTerminator`] if the last byte of `bytes` is not zero and [`Error::InteriorNulByte`] if a zero byte
+    /// is found anywhere else.
+    ///
+    /// ```rust
     /// use unixstring::UnixString;
     ///
-    /// let home_dir = PathBuf::from("/home/user");
-    /// let unix_string = UnixString::try_from(home_dir.clone()).unwrap();
+    /// assert!(UnixString::from_vec_with_nul(b"abc\0".to_vec()).is_ok());
     ///
-    /// assert_eq!(&home_dir, unix_string.as_path())
+    /// // Invalid: no nul terminator
+    /// assert!(UnixString::from_vec_with_nul(b"abc".to_vec()).is_err());
+    ///
+    /// // Invalid: an interior nul byte was found
+    /// assert!(UnixString::from_vec_with_nul(b"a\0bc\0".to_vec()).is_err());
     /// ```
-    pub fn as_path(&self) -> &Path {
-        Path::new(self.as_os_str())
-    }
-
-    /// Converts the `UnixString` to a [`CStr`] slice. This always succeeds and is zero cost.
-    pub fn as_c_str(&self) -> &CStr {
-        // Safety: we do not allow a UnixString to be built without a nul terminator, therefore this cannot fail.
-        //
-        // If you ever do see this function fail, please notify this at github.com/vrmiguel/unixstring
-        CStr::from_bytes_with_nul(&self.inner).unwrap()
+    pub fn from_vec_with_nul(bytes: Vec<u8>) -> Result<Self> {
+        match bytes.split_last() {
+            Some((0, content)) => match find_nul_byte(content) {
+                Some(nul_pos) => Err(Error::InteriorNulByte { position: nul_pos }),
+                None => Ok(Self { inner: bytes }),
+            },
+            _ => Err(Error::MissingNulTerminator),
+        }
     }
 
-    /// Tries to convert this `UnixString` into a [`&str`](str).
+    /// Creates a [`UnixString`](UnixString) from the bytes of a C string, which must include its nul terminator.
     ///
-    /// The terminating nul byte will not be included in the `&str`.
+    /// This is the same as [`UnixString::from_vec_with_nul`](UnixString::from_vec_with_nul): unlike
+    /// [`UnixString::from_bytes`](UnixString::from_bytes), a missing terminator is reported as
+    /// [`Error::MissingNulTerminator`] instead of being appended, which helps catching bugs where it was accidentally dropped.
     ///
-    /// If this byte string is not valid UTF-8, then an error is returned indicating the first invalid byte found and the length of the error.
-    /// If instead you wish for a lossy conversion to &str, then use [`to_str_lossy`](UnixString::to_string_lossy).
-    pub fn to_str(&self) -> Result<&str> {
-        Ok(std::str::from_utf8(self.inner_without_nul_terminator())?)
-    }
+    /// ```rust
+    /// use unixstring::{Error, UnixString};
+    ///
+    /// assert!(UnixString::from_c_string_bytes(b"/etc/hosts\0".to_vec()).is_ok());
+    ///
+    /// assert_eq!(
+    ///     UnixString::from_c_string_bytes(b"/etc/hosts".to_vec()).unwrap_err(),
+    ///     Error::MissingNulTerminator
+    /// );
+    /// ```
+    pub fn from_c_string_bytes(bytes: Vec<u8>) -> Result<Self> {
+        Self::from_vec_with_nul(bytes)
+    }
+
+    /// Creates a [`UnixString`](UnixString) given a nul-terminated `Vec` of bytes, without checking its contents.
+    ///
+    /// See the safe version, [`UnixString::from_vec_with_nul`](UnixString::from_vec_with_nul), for more details.
+    ///
+    /// # Safety
+    ///
+    /// The caller must ensure that `bytes` has a zero byte at its last position and no zero bytes anywhere else.
+    /// Building a `UnixString` that breaks this invariant may lead to undefined behavior when passing it over FFI.
+    pub unsafe fn from_vec_with_nul_unchecked(bytes: Vec<u8>) -> Self {
+        debug_assert!(Self::from_vec_with_nul(bytes.clone()).is_ok());
+
+        Self { inner: bytes }
+    }
+
+    /// Constructs a new, empty `UnixString` with the specified capacity.
+    ///
+    /// The `UnixString`'s inner vector will be able to hold exactly `capacity` elements without
+    /// reallocating.
+    ///
+    /// This function will always allocate enough to fit the null terminator byte, even if the given capacity is 0.
+    ///
+    ///
+    /// # Panics
+    ///
+    /// Panics if the new capacity exceeds `isize::MAX - 1` bytes, and aborts if the allocation fails.
+    ///
+    /// When `capacity` comes from untrusted input, consider [`UnixString::try_with_capacity`](UnixString::try_with_capacity) instead.
+    pub fn with_capacity(capacity: usize) -> Self {
+        // `usize::MAX + 1` can't be allocated anyway, so requesting `usize::MAX` panics just the same
+        let mut inner = Vec::with_capacity(capacity.saturating_add(1));
+        inner.push(0);
+
+        Self { inner }
+    }
+
+    /// Constructs a new, empty `UnixString` with the specified capacity, returning an error if the allocation fails.
+    ///
+    /// Unlike [`UnixString::with_capacity`](UnixString::with_capacity), this never panics nor aborts
+    /// the process: a capacity that's too large results in [`Error::Allocation`].
+    ///
+    /// ```rust
+    /// use unixstring::UnixString;
+    ///
+    /// let unx = UnixString::try_with_capacity(49).unwrap();
+    /// assert!(unx.capacity() >= 50);
+    ///
+    /// assert!(UnixString::try_with_capacity(usize::MAX).is_err());
+    /// ```
+    pub fn try_with_capacity(capacity: usize) -> Result<Self> {
+        // Just like `Vec::with_capacity`, request exactly the needed capacity
+        Self::try_with_capacity_exact(capacity)
+    }
+
+    /// Constructs a new, empty `UnixString` requesting exactly enough memory to hold `capacity` bytes and the nul terminator.
+    ///
+    /// Unlike [`UnixString::with_capacity`](UnixString::with_capacity), this uses [`Vec::reserve_exact`](alloc::vec::Vec::reserve_exact),
+    /// which is useful in memory-tight scenarios. Do note that the allocator may still give the `UnixString` more space than requested.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the new capacity exceeds `isize::MAX - 1` bytes, and aborts if the allocation fails.
+    ///
+    /// When `capacity` comes from untrusted input, consider [`UnixString::try_with_capacity_exact`](UnixString::try_with_capacity_exact) instead.
+    ///
+    /// ```rust
+    /// use unixstring::UnixString;
+    ///
+    /// let unx = UnixString::with_capacity_exact(49);
+    /// assert!(unx.capacity() >= 50);
+    /// assert!(unx.is_empty());
+    /// ```
+    pub fn with_capacity_exact(capacity: usize) -> Self {
+        let mut inner = Vec::new();
+        // `usize::MAX + 1` can't be allocated anyway, so reserving `usize::MAX` panics just the same
+        inner.reserve_exact(capacity.saturating_add(1));
+        inner.push(0);
+
+        Self { inner }
+    }
+
+    /// Constructs a new, empty `UnixString` requesting exactly enough memory to hold `capacity` bytes and the nul terminator,
+    /// returning an error if the allocation fails.
+    ///
+    /// Works just like [`UnixString::with_capacity_exact`](UnixString::with_capacity_exact), but never panics nor aborts
+    /// the process: a capacity that's too large results in [`Error::Allocation`].
+    ///
+    /// ```rust
+    /// use unixstring::UnixString;
+    ///
+    /// let unx = UnixString::try_with_capacity_exact(49).unwrap();
+    /// assert!(unx.capacity() >= 50);
+    ///
+    /// assert!(UnixString::try_with_capacity_exact(usize::MAX).is_err());
+    /// ```
+    pub fn try_with_capacity_exact(capacity: usize) -> Result<Self> {
+        let mut inner = Vec::new();
+        // `usize::MAX + 1` can't be allocated anyway, so reserving `usize::MAX` fails just the same
+        inner.try_reserve_exact(capacity.saturating_add(1))?;
+        inner.push(0);
+
+        Ok(Self { inner })
+    }
+
+    /// Constructs a new, empty `UnixString` with enough capacity to hold the bytes of `value` and a nul terminator.
+    ///
+    /// The contents of `value` are not copied: this is useful when the buffer will later be filled through FFI,
+    /// e.g. with [`UnixString::as_mut_ptr`](UnixString::as_mut_ptr).
+    ///
+    /// ```rust
+    /// use std::path::Path;
+    ///
+    /// use unixstring::UnixString;
+    ///
+    /// let unx = UnixString::with_capacity_for(Path::new("/home/user"));
+    ///
+    /// assert!(unx.is_empty());
+    /// // Room for the ten bytes of the path plus the nul terminator
+    /// assert!(unx.capacity() >= 11);
+    /// ```
+    #[cfg(all(feature = "std", unix))]
+    pub fn with_capacity_for(value: impl AsRef<OsStr>) -> Self {
+        Self::with_capacity(value.as_ref().len())
+    }
+
+    /// Clones a raw C string into an `UnixString`.
+    ///
+    /// The total size of the raw C string must be smaller than `isize::MAX` **bytes**
+    /// in memory due to calling the `slice::from_raw_parts` function.
+    ///
+    /// # Safety
+    ///
+    /// This method is unsafe for a number of reasons:
+    ///
+    /// * There is no guarantee to the validity of `ptr`.
+    /// * There is no guarantee that the memory pointed to by `ptr` contains a
+    ///   valid nul terminator byte at the end of the string.
+    /// * It is not guaranteed that the memory pointed by `ptr` won't change
+    ///   before the `UnixString` has been constructed.
+    ///
+    /// See [`CStr::from_ptr`](std::ffi::CStr::from_ptr) for more info.
+    pub unsafe fn from_ptr(ptr: *const libc::c_char) -> Self {
+        CStr::from_ptr(ptr).to_owned().into()
+    }
+
+    /// Copies `len` bytes starting at `ptr` into a new `UnixString`.
+    ///
+    /// Unlike [`UnixString::from_ptr`](UnixString::from_ptr), the given memory does not need to be nul-terminated,
+    /// which makes this useful for C APIs that return a pointer and a length, such as `readlink`.
+    ///
+    /// This method fails if the copied bytes have a zero byte anywhere but at their end.
+    ///
+    /// # Safety
+    ///
+    /// * `ptr` must be valid for reads of `len` bytes.
+    /// * `len` must be no larger than `isize::MAX`.
+    /// * The memory pointed to by `ptr` must not change before the `UnixString` has been constructed.
+    ///
+    /// See [`slice::from_raw_parts`](core::slice::from_raw_parts) for more info.
+    ///
+    /// ```rust
+    /// # use unixstring::Result;
+    /// use unixstring::UnixString;
+    /// # fn main() -> Result<()> {
+    /// let bytes = b"/usr/bin/env";
+    ///
+    /// let unx = unsafe { UnixString::from_ptr_with_len(bytes.as_ptr().cast(), 8)? };
+    ///
+    /// assert_eq!(unx.as_bytes(), b"/usr/bin");
+    /// # Ok(()) }
+    /// ```
+    pub unsafe fn from_ptr_with_len(ptr: *const libc::c_char, len: usize) -> Result<Self> {
+        let bytes = core::slice::from_raw_parts(ptr.cast::<u8>(), len);
+        let bytes = strip_nul_terminator(bytes)?;
+
+        let mut inner = Vec::with_capacity(bytes.len() + 1);
+        inner.extend_from_slice(bytes);
+        inner.push(b'\0');
+
+        Ok(Self { inner })
+    }
+
+    /// Returns an inner pointer to the data this `UnixString` contains.
+    ///
+    /// The returned pointer will be valid for as long as the given `UnixString` is, and points
+    /// to a null-terminated contiguous region of memory.
+    ///
+    /// *Note*: The returned pointer is read-only and writing to it in any way causes undefined behavior.
+    ///
+    /// You must ensure that the underlying memory is not
+    /// freed too early. If the `UnixString` is deallocated then the pointer becomes dangling.
+    ///
+    /// See [`CStr::as_ptr`](std::ffi::CStr::as_ptr) for more info.
+    ///
+    pub fn as_ptr(&self) -> *const libc::c_char {
+        self.as_c_str().as_ptr()
+    }
+
+    /// Returns the two raw pointers spanning the contents of this `UnixString`, without its nul terminator.
+    ///
+    /// The returned range is half-open: `start` points to the first byte of the `UnixString` and `end` points one past its
+    /// last content byte, which is the position of the nul terminator. For an empty `UnixString`, `start == end`.
+    ///
+    /// The same caveats of [`UnixString::as_ptr`](UnixString::as_ptr) apply to the returned pointers.
+    ///
+    /// See also: [`slice::as_ptr_range`].
+    ///
+    /// ```rust
+    /// use unixstring::UnixString;
+    ///
+    /// let unx = UnixString::from_string("abc".into()).unwrap();
+    /// let range = unx.as_ptr_range();
+    ///
+    /// assert_eq!(range.start, unx.as_ptr());
+    /// assert_eq!(range.end as usize - range.start as usize, unx.len());
+    /// assert_eq!(unsafe { *range.end }, 0);
+    /// ```
+    pub fn as_ptr_range(&self) -> Range<*const libc::c_char> {
+        let Range { start, end } = self.as_bytes().as_ptr_range();
+
+        start as *const libc::c_char..end as *const libc::c_char
+    }
+
+    /// Returns a pointer to the contents of this `UnixString` along with its length *without* the nul terminator.
+    ///
+    /// This is meant for C functions that take a buffer and its length, such as `write(2)`.
+    /// The same caveats of [`UnixString::as_ptr`](UnixString::as_ptr) apply to the returned pointer.
+    ///
+    /// ```rust
+    /// use unixstring::UnixString;
+    ///
+    /// let unx = UnixString::from_string("abc".into()).unwrap();
+    /// let (ptr, len) = unx.as_ptr_and_len();
+    ///
+    /// assert_eq!(ptr, unx.as_ptr());
+    /// assert_eq!(len, 3);
+    /// ```
+    pub fn as_ptr_and_len(&self) -> (*const libc::c_char, usize) {
+        (self.as_ptr(), self.len())
+    }
+
+    /// Returns a pointer to the contents of this `UnixString` along with its length *including* the nul terminator.
+    ///
+    /// The same caveats of [`UnixString::as_ptr`](UnixString::as_ptr) apply to the returned pointer.
+    ///
+    /// ```rust
+    /// use unixstring::UnixString;
+    ///
+    /// let unx = UnixString::from_string("abc".into()).unwrap();
+    /// let (ptr, len) = unx.as_ptr_and_len_with_nul();
+    ///
+    /// assert_eq!(ptr, unx.as_ptr());
+    /// assert_eq!(len, 4);
+    /// ```
+    pub fn as_ptr_and_len_with_nul(&self) -> (*const libc::c_char, usize) {
+        (self.as_ptr(), self.len_with_nul())
+    }
+
+    /// Builds a null-terminated array of pointers to the given `UnixString`s, as expected by `execv`-like functions.
+    ///
+    /// Each element of the returned `Vec` is the [`as_ptr`](UnixString::as_ptr) of the corresponding
+    /// `UnixString`, followed by a trailing null pointer.
+    ///
+    /// The pointers are only valid for as long as `strings` is alive and unmodified: the `UnixString`s
+    /// must outlive the returned array, so keep both around until the FFI call using it returns.
+    ///
+    /// ```rust
+    /// use unixstring::UnixString;
+    ///
+    /// let args = [
+    ///     UnixString::from_string("ls".into()).unwrap(),
+    ///     UnixString::from_string("-l".into()).unwrap(),
+    /// ];
+    ///
+    /// let argv = UnixString::build_argv(&args);
+    ///
+    /// assert_eq!(argv.len(), 3);
+    /// assert_eq!(argv[0], args[0].as_ptr());
+    /// assert!(argv[2].is_null());
+    ///
+    /// // `args` and `argv` can now be passed to e.g. `libc::execv(argv[0], argv.as_ptr())`
+    /// ```
+    pub fn build_argv(strings: &[UnixString]) -> Vec<*const libc::c_char> {
+        let mut argv = Vec::with_capacity(strings.len() + 1);
+        argv.extend(strings.iter().map(UnixString::as_ptr));
+        argv.push(core::ptr::null());
+
+        argv
+    }
+
+    fn inner_without_nul_terminator(&self) -> &[u8] {
+        &self.inner[0..self.inner.len() - 1]
+    }
+
+    /// Converts the `UnixString` to an [`OsStr`] slice. This always succeeds and is zero cost. The terminating nul byte will not be included in the `OsStr` slice.
+    /// ```rust
+    /// use std::{convert::TryFrom, path::PathBuf};
+    ///
+    /// use unixstring::UnixString;
+    ///
+    /// let logs = PathBuf::from("/var/log/journal");
+    /// let unix_string = UnixString::try_from(logs.clone()).unwrap();
+    ///
+    /// assert_eq!(
+    ///     logs.as_os_str(),
+    ///     unix_string.as_os_str()
+    /// )
+    ///
+    /// ```
+    #[cfg(all(feature = "std", unix))]
+    pub fn as_os_str(&self) -> &OsStr {
+        OsStr::from_bytes(self.inner_without_nul_terminator())
+    }
+
+    /// Converts the `UnixString` to a [`Path`] slice. This always succeeds and is zero cost.
+    /// The terminating nul byte will not be included in the `Path` slice.
+    /// ```rust
+    /// use std::{convert::TryFrom, path::PathBuf};
+    ///
+    /// use unixstring::UnixString;
+    ///
+    /// let home_dir = PathBuf::from("/home/user");
+    /// let unix_string = UnixString::try_from(home_dir.clone()).unwrap();
+    ///
+    /// assert_eq!(&home_dir, unix_string.as_path())
+    /// ```
+    #[cfg(all(feature = "std", unix))]
+    pub fn as_path(&self) -> &Path {
+        Path::new(self.as_os_str())
+    }
+
+    /// Reads the target of the symbolic link this `UnixString` points to.
+    ///
+    /// This calls `readlink(2)`, growing the buffer it reads into until the whole target fits.
+    ///
+    /// ```rust
+    /// # use std::io::Result;
+    /// use std::os::unix::fs::symlink;
+    ///
+    /// use unixstring::UnixString;
+    /// # fn main() -> Result<()> {
+    /// # let dir = std::env::temp_dir().join(format!("unixstring-doc-read-link-{}", std::process::id()));
+    /// # std::fs::create_dir_all(&dir)?;
+    /// let link = dir.join("link");
+    /// # let _ = std::fs::remove_file(&link);
+    /// symlink("/usr/lib", &link)?;
+    ///
+    /// let link = UnixString::from_pathbuf(link).unwrap();
+    ///
+    /// assert_eq!(link.read_link()?, "/usr/lib");
+    /// # std::fs::remove_dir_all(&dir)?;
+    /// # Ok(()) }
+    /// ```
+    #[cfg(all(feature = "std", unix))]
+    pub fn read_link(&self) -> std::io::Result<UnixString> {
+        let mut buf: Vec<u8> = Vec::with_capacity(128);
+
+        loop {
+            let capacity = buf.capacity();
+            let read = unsafe { libc::readlink(self.as_ptr(), buf.as_mut_ptr().cast(), capacity) };
+            if read == -1 {
+                return Err(std::io::Error::last_os_error());
+            }
+
+            let read = read as usize;
+            // If the whole buffer was filled, the target may have been truncated
+            if read < capacity {
+                // Safety: `readlink` initialized the first `read` bytes of the buffer
+                unsafe { buf.set_len(read) };
+                // `readlink` does not nul-terminate its output, but a path can't contain a zero byte
+                debug_assert!(find_nul_byte(&buf).is_none());
+                buf.push(b'\0');
+
+                return Ok(Self { inner: buf });
+            }
+
+            buf.reserve(capacity * 2);
+        }
+    }
+
+    /// Converts the `UnixString` to a [`CStr`] slice. This always succeeds and is zero cost.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the invariants of the `UnixString` were broken through unsafe code, e.g. by writing
+    /// through [`UnixString::as_mut_ptr`](UnixString::as_mut_ptr). Prefer
+    /// [`UnixString::try_as_c_str`](UnixString::try_as_c_str) after such raw-pointer mutation.
+    pub fn as_c_str(&self) -> &CStr {
+        // Safety: we do not allow a UnixString to be built without a nul terminator, therefore this cannot fail.
+        //
+        // If you ever do see this function fail, please notify this at github.com/vrmiguel/unixstring
+        CStr::from_bytes_with_nul(&self.inner).unwrap()
+    }
+
+    /// Converts the `UnixString` to a [`CStr`] slice, failing instead of panicking if the `UnixString` is not valid.
+    ///
+    /// This performs the same checks as [`UnixString::validate`](UnixString::validate), so it's the safe choice
+    /// when the `UnixString` may have been mutated through a raw pointer, such as the one returned by
+    /// [`UnixString::as_mut_ptr`](UnixString::as_mut_ptr).
+    ///
+    /// ```rust
+    /// use unixstring::{Error, UnixString};
+    ///
+    /// let mut unx = UnixString::from_string("hello".into()).unwrap();
+    /// assert_eq!(unx.try_as_c_str().unwrap().to_bytes(), b"hello");
+    ///
+    /// // Mock an FFI call which writes a zero byte in the middle of the string
+    /// unsafe { unx.as_mut_ptr().add(2).write(0) };
+    ///
+    /// assert_eq!(unx.try_as_c_str().unwrap_err(), Error::InteriorNulByte { position: 2 });
+    /// ```
+    pub fn try_as_c_str(&self) -> Result<&CStr> {
+        self.validate()?;
+
+        Ok(self.as_c_str())
+    }
+
+    /// Borrows this `UnixString` as a [`Cow`]`<`[`CStr`]`>`.
+    ///
+    /// This always returns [`Cow::Borrowed`] and is zero cost.
+    ///
+    /// ```rust
+    /// use std::borrow::Cow;
+    ///
+    /// use unixstring::UnixString;
+    ///
+    /// let unx = UnixString::from_string("/usr/lib".into()).unwrap();
+    ///
+    /// assert!(matches!(unx.as_cow_c_str(), Cow::Borrowed(c_str) if c_str == unx.as_c_str()));
+    /// ```
+    pub fn as_cow_c_str(&self) -> Cow<'_, CStr> {
+        Cow::Borrowed(self.as_c_str())
+    }
+
+    /// Converts this `UnixString` into a [`Cow`]`<`[`CStr`]`>`.
+    ///
+    /// This always returns [`Cow::Owned`], holding the result of [`UnixString::into_cstring`](UnixString::into_cstring).
+    ///
+    /// ```rust
+    /// use std::borrow::Cow;
+    ///
+    /// use unixstring::UnixString;
+    ///
+    /// let unx = UnixString::from_string("/usr/lib".into()).unwrap();
+    ///
+    /// assert!(matches!(unx.into_cow_c_str(), Cow::Owned(c_string) if c_string.as_bytes() == b"/usr/lib"));
+    /// ```
+    pub fn into_cow_c_str(self) -> Cow<'static, CStr> {
+        Cow::Owned(self.into_cstring())
+    }
+
+    /// Tries to convert this `UnixString` into a [`&str`](str).
+    ///
+    /// The terminating nul byte will not be included in the `&str`.
+    ///
+    /// If this byte string is not valid UTF-8, then an error is returned indicating the first invalid byte found and the length of the error.
+    /// If instead you wish for a lossy conversion to &str, then use [`to_str_lossy`](UnixString::to_string_lossy).
+    pub fn to_str(&self) -> Result<&str> {
+        Ok(core::str::from_utf8(self.inner_without_nul_terminator())?)
+    }
+
+    /// Extends a `UnixString` by copying from a raw C string
+    ///
+    /// # Safety
+    ///
+    /// This method is unsafe for a number of reasons:
+    /// * The total size of the raw C string must be smaller than `isize::MAX` bytes in memory.
+    /// * There is no guarantee to the validity of `ptr`.
+    /// * There is no guarantee that the memory pointed to by `ptr` contains a
+    ///   valid nul terminator byte at the end of the string.
+    /// * It is not guaranteed that the memory pointed by `ptr` won't change
+    ///   while this operation occurs.
+    ///
+    /// This function uses [`CStr::from_ptr`](std::ffi::CStr::from_ptr) internally, so check it out for more information.
+    ///
+    pub unsafe fn extend_from_ptr(&mut self, ptr: *const libc::c_char) -> Result<()> {
+        let cstr = CStr::from_ptr(ptr);
+        let bytes = cstr.to_bytes();
+        self.push_bytes(bytes)
+    }
+
+    /// This function will check if the inner bytes of this `UnixString` (without its null terminator) consists of valid UTF-8, and if so, returns a `String` reusing the `UnixString`'s buffer.
+    ///
+    /// If the inner bytes contain invalid UTF-8, then a new `String` will be allocated with the invalid bytes replaced with the Unicode replacement codepoint.
+    pub fn into_string_lossy(self) -> String {
+        let bytes = self.into_bytes();
+        match String::from_utf8_lossy(&bytes) {
+            Cow::Owned(new_string) => new_string,
+            Cow::Borrowed(_) => {
+                // Safety: String::from_utf8_lossy returns a reference when the supplied input
+                // is valid UTF-8. Constructing a String unchecked, therefore, is safe.
+                unsafe { String::from_utf8_unchecked(bytes) }
+            }
+        }
+    }
+
+    /// Converts this `UnixString` into a `String` just like [`UnixString::into_string_lossy`](UnixString::into_string_lossy),
+    /// also returning how many replacement characters were introduced by the conversion.
+    ///
+    /// A count of zero means that the bytes were valid UTF-8 and, in that case, the buffer of the `UnixString` is reused.
+    /// Replacement characters which were already present in the `UnixString` are not counted.
+    ///
+    /// ```rust
+    /// use unixstring::UnixString;
+    ///
+    /// let unx = UnixString::from_bytes(vec![b'a', 0xFF, b'b', 0xFE]).unwrap();
+    ///
+    /// assert_eq!(unx.into_string_lossy_counted(), ("a\u{FFFD}b\u{FFFD}".to_string(), 2));
+    /// ```
+    pub fn into_string_lossy_counted(self) -> (String, usize) {
+        let bytes = match String::from_utf8(self.into_bytes()) {
+            Ok(string) => return (string, 0),
+            Err(err) => err.into_bytes(),
+        };
+
+        let mut string = String::with_capacity(bytes.len());
+        let mut replacements = 0;
+        let mut rest = &bytes[..];
+        while !rest.is_empty() {
+            let (valid, invalid_len) = split_valid_utf8(rest);
+            string.push_str(valid);
+            if invalid_len > 0 {
+                string.push(char::REPLACEMENT_CHARACTER);
+                replacements += 1;
+            }
+            rest = &rest[valid.len() + invalid_len..];
+        }
+
+        (string, replacements)
+    }
+
+    /// Converts a `UnixString` into a String if the bytes of the `UnixString` are valid UTF-8.
+    ///
+    /// If you are sure that the byte slice is valid UTF-8 and you don’t want to incur the overhead of the validity check, there is an unsafe version of this function, [`UnixString::into_string_unchecked`](UnixString::into_string_unchecked), which has the same behavior but skips the check.
+    ///
+    /// If the validity check passes, the resulting `String` will reuse the allocation of the `UnixString`'s inner buffer and no copy will be done.
+    ///
+    /// If you need a `&str` instead of a `String`, consider [`UnixString::as_str`](UnixString::to_str).
+    pub fn into_string(self) -> Result<String> {
+        Ok(String::from_utf8(self.into_bytes())?)
+    }
+
+    /// Converts a `UnixString` into a `String` without checking that the
+    /// string contains valid UTF-8.
+    ///
+    /// See the safe version, [`UnixString::into_string`](UnixString::into_string), for more details.
+    ///
+    /// # Safety
+    ///
+    /// This function is unsafe because it does not check that the bytes passed
+    /// to it are valid UTF-8. If this constraint is violated, it may cause
+    /// memory unsafety issues with future users of the `String`, as the rest of
+    /// the standard library assumes that `String`s are valid UTF-8.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use unixstring::UnixString;
+    ///
+    /// let baby = UnixString::from_bytes(vec![0xF0, 0x9F, 0x91, 0xB6]).unwrap();
+    ///
+    /// let baby = unsafe {
+    ///     baby.into_string_unchecked()
+    /// };
+    ///
+    /// assert_eq!("👶", baby);
+    /// ```
+    pub unsafe fn into_string_unchecked(self) -> String {
+        String::from_utf8_unchecked(self.into_bytes())
+    }
+
+    /// This function will check if the inner bytes of this `UnixString` (without its null terminator) consists of valid UTF-8, and if so, returns a string slice without copying.
+    ///
+    /// If the inner bytes contain invalid UTF-8, then a new `String` will be allocated with the invalid bytes replaced with the Unicode replacement codepoint.
+    pub fn to_string_lossy(&self) -> Cow<'_, str> {
+        self.as_c_str().to_string_lossy()
+    }
+
+    /// Returns an iterator over the `char`s of this `UnixString`, decoding its bytes as UTF-8 lossily.
+    ///
+    /// Each invalid UTF-8 sequence is yielded as a single Unicode replacement character, just like in
+    /// [`UnixString::to_string_lossy`](UnixString::to_string_lossy), but nothing is allocated.
+    /// The nul terminator is never yielded.
+    ///
+    /// ```rust
+    /// use unixstring::UnixString;
+    ///
+    /// let unx = UnixString::from_bytes(vec![b'a', 0xFF, b'b']).unwrap();
+    ///
+    /// assert_eq!(unx.chars_lossy().collect::<String>(), "a\u{FFFD}b");
+    /// ```
+    pub fn chars_lossy(&self) -> impl Iterator<Item = char> + '_ {
+        self.char_indices_lossy().map(|(_idx, ch)| ch)
+    }
+
+    /// Returns an iterator over the `char`s of this `UnixString` along with their byte positions, decoding its bytes as UTF-8 lossily.
+    ///
+    /// Works just like [`UnixString::chars_lossy`](UnixString::chars_lossy). The position yielded along with a
+    /// replacement character is the position of the invalid sequence it replaces.
+    ///
+    /// ```rust
+    /// use unixstring::UnixString;
+    ///
+    /// let unx = UnixString::from_bytes(vec![0xC3, 0xA9, 0xFF, b'b']).unwrap();
+    ///
+    /// assert_eq!(
+    ///     unx.char_indices_lossy().collect::<Vec<_>>(),
+    ///     [(0, 'é'), (2, '\u{FFFD}'), (3, 'b')]
+    /// );
+    /// ```
+    pub fn char_indices_lossy(&self) -> impl Iterator<Item = (usize, char)> + '_ {
+        let mut rest = self.as_bytes();
+        let mut offset = 0;
+        let mut valid = "".char_indices();
+        let mut valid_offset = 0;
+        let mut invalid_offset = None;
+
+        core::iter::from_fn(move || loop {
+            if let Some((idx, ch)) = valid.next() {
+                return Some((valid_offset + idx, ch));
+            }
+            if let Some(idx) = invalid_offset.take() {
+                return Some((idx, char::REPLACEMENT_CHARACTER));
+            }
+            if rest.is_empty() {
+                return None;
+            }
+
+            let (valid_str, invalid_len) = split_valid_utf8(rest);
+            let valid_len = valid_str.len();
+
+            valid = valid_str.char_indices();
+            valid_offset = offset;
+            if invalid_len > 0 {
+                invalid_offset = Some(offset + valid_len);
+            }
+
+            offset += valid_len + invalid_len;
+            rest = &rest[valid_len + invalid_len..];
+        })
+    }
+
+    /// Gets the underlying byte view of this `UnixString` *without* the nul terminator.
+    /// ```rust
+    /// use unixstring::UnixString;
+    ///
+    /// let bytes = b"abc\0".to_vec();
+    /// let unix_string = UnixString::from_bytes(bytes).unwrap();
+    ///
+    /// assert_eq!(
+    ///     unix_string.as_bytes(),
+    ///     &[b'a', b'b', b'c']
+    /// );
+    pub fn as_bytes(&self) -> &[u8] {
+        self.inner_without_nul_terminator()
+    }
+
+    /// Gets a mutable view over the bytes of this `UnixString` *without* the nul terminator.
+    ///
+    /// The nul terminator can't be reached or moved through this slice, but do note that writing a zero byte
+    /// into it creates an interior nul byte. If you do so, [`UnixString::validate`](UnixString::validate) will fail
+    /// and the string will be cut short once it's passed over FFI.
+    ///
+    /// ```rust
+    /// use unixstring::UnixString;
+    ///
+    /// let mut unx = UnixString::from_string("/home/user".into()).unwrap();
+    ///
+    /// unx.as_mut_bytes()[1] = b'H';
+    ///
+    /// assert_eq!(unx.as_bytes_with_nul(), b"/Home/user\0");
+    /// ```
+    pub fn as_mut_bytes(&mut self) -> &mut [u8] {
+        let len = self.len();
+        &mut self.inner[..len]
+    }
+
+    /// Gets a mutable view over all the bytes of this `UnixString`, *including* its nul terminator.
+    ///
+    /// This can be useful for FFI functions which fill a whole buffer, writing their own nul terminator.
+    ///
+    /// # Safety
+    ///
+    /// Once the returned slice is dropped, its last byte must be zero and no other byte may be zero.
+    /// Check that this still holds with [`UnixString::validate`](UnixString::validate) whenever in doubt.
+    ///
+    /// ```rust
+    /// use unixstring::UnixString;
+    ///
+    /// let mut unx = UnixString::from_string("abc".into()).unwrap();
+    ///
+    /// let bytes = unsafe { unx.as_bytes_with_nul_mut() };
+    /// bytes.copy_from_slice(b"xyz\0");
+    ///
+    /// assert!(unx.validate().is_ok());
+    /// assert_eq!(unx.as_bytes(), b"xyz");
+    /// ```
+    pub unsafe fn as_bytes_with_nul_mut(&mut self) -> &mut [u8] {
+        &mut self.inner
+    }
+
+    /// Returns an iterator over the bytes of this `UnixString`.
+    ///
+    /// The nul terminator is never yielded.
+    ///
+    /// ```rust
+    /// use unixstring::UnixString;
+    ///
+    /// let unx = UnixString::from_string("abc".into()).unwrap();
+    ///
+    /// assert_eq!(unx.bytes().collect::<Vec<_>>(), b"abc");
+    /// ```
+    pub fn bytes(&self) -> impl Iterator<Item = u8> + '_ {
+        self.as_bytes().iter().copied()
+    }
+
+    /// Returns an iterator over the bytes of this `UnixString` along with their positions.
+    ///
+    /// The nul terminator is never yielded.
+    ///
+    /// ```rust
+    /// use unixstring::UnixString;
+    ///
+    /// let unx = UnixString::from_string("abc".into()).unwrap();
+    /// let mut indices = unx.byte_indices();
+    ///
+    /// assert_eq!(indices.next(), Some((0, b'a')));
+    /// assert_eq!(indices.next(), Some((1, b'b')));
+    /// assert_eq!(indices.next(), Some((2, b'c')));
+    /// assert_eq!(indices.next(), None);
+    /// ```
+    pub fn byte_indices(&self) -> impl Iterator<Item = (usize, u8)> + '_ {
+        self.bytes().enumerate()
+    }
+
+    /// Returns an iterator over `size` bytes of this `UnixString` at a time, starting at its beginning.
+    ///
+    /// The chunks don't overlap and the last one may be shorter than `size`. The nul terminator is never included.
+    /// See also: [`slice::chunks`].
+    ///
+    /// # Panics
+    ///
+    /// Panics if `size` is zero.
+    ///
+    /// ```rust
+    /// use unixstring::UnixString;
+    ///
+    /// let unx = UnixString::from_string("abcde".into()).unwrap();
+    /// let chunks: Vec<&[u8]> = unx.chunks(2).collect();
+    ///
+    /// assert_eq!(chunks, [&b"ab"[..], b"cd", b"e"]);
+    /// ```
+    pub fn chunks(&self, size: usize) -> impl Iterator<Item = &[u8]> + '_ {
+        self.as_bytes().chunks(size)
+    }
+
+    /// Returns an iterator over all contiguous windows of `size` bytes of this `UnixString`.
+    ///
+    /// The windows overlap and none is yielded if the `UnixString` is shorter than `size`.
+    /// The nul terminator is never included. See also: [`slice::windows`].
+    ///
+    /// # Panics
+    ///
+    /// Panics if `size` is zero.
+    ///
+    /// ```rust
+    /// use unixstring::UnixString;
+    ///
+    /// let unx = UnixString::from_string("abcd".into()).unwrap();
+    /// let windows: Vec<&[u8]> = unx.windows(3).collect();
+    ///
+    /// assert_eq!(windows, [&b"abc"[..], b"bcd"]);
+    /// ```
+    pub fn windows(&self, size: usize) -> impl Iterator<Item = &[u8]> + '_ {
+        self.as_bytes().windows(size)
+    }
+
+    /// Converts a `UnixString` into an [`OsString`].
+    ///
+    /// This operation is zero-cost.
+    ///
+    /// If you need a `&OsStr` instead of an `OsString`, consider [`UnixString::as_os_str`](UnixString::as_os_str).
+    #[cfg(all(feature = "std", unix))]
+    pub fn into_os_string(self) -> OsString {
+        self.into()
+    }
+
+    /// Copies the bytes of this `UnixString`, without its nul terminator, into a new [`OsString`].
+    ///
+    /// This is the non-consuming counterpart of [`UnixString::into_os_string`](UnixString::into_os_string).
+    ///
+    /// ```rust
+    /// use std::ffi::OsString;
+    ///
+    /// use unixstring::UnixString;
+    ///
+    /// let unx = UnixString::from_string("/home/user".into()).unwrap();
+    ///
+    /// assert_eq!(unx.to_os_string(), OsString::from("/home/user"));
+    /// ```
+    #[cfg(all(feature = "std", unix))]
+    pub fn to_os_string(&self) -> OsString {
+        self.as_os_str().to_os_string()
+    }
+
+    /// Converts a `UnixString` into a [`PathBuf`].
+    ///
+    /// This operation is zero-cost.
+    ///
+    /// If you need a `&Path` instead of a `PathBuf`, consider [`UnixString::as_path`](UnixString::as_path).
+    #[cfg(all(feature = "std", unix))]
+    pub fn into_pathbuf(self) -> PathBuf {
+        self.into()
+    }
+
+    /// Copies the bytes of this `UnixString`, without its nul terminator, into a new [`PathBuf`].
+    ///
+    /// This is the non-consuming counterpart of [`UnixString::into_pathbuf`](UnixString::into_pathbuf),
+    /// just like [`Path::to_path_buf`](std::path::Path::to_path_buf).
+    ///
+    /// ```rust
+    /// use std::path::PathBuf;
+    ///
+    /// use unixstring::UnixString;
+    ///
+    /// let unx = UnixString::from_string("/home/user".into()).unwrap();
+    ///
+    /// assert_eq!(unx.to_path_buf(), PathBuf::from("/home/user"));
+    /// ```
+    #[cfg(all(feature = "std", unix))]
+    pub fn to_path_buf(&self) -> PathBuf {
+        self.as_path().to_path_buf()
+    }
+
+    /// Converts a `UnixString` into a [`CString`].
+    ///
+    /// This operation is zero-cost.
+    ///
+    /// If you need a `&CStr` instead of a `CString`, consider [`UnixString::as_c_str`](UnixString::as_c_str).
+    pub fn into_cstring(self) -> CString {
+        self.into()
+    }
+
+    /// Copies the bytes of this `UnixString`, including its nul terminator, into a new [`CString`].
+    ///
+    /// This is the non-consuming counterpart of [`UnixString::into_cstring`](UnixString::into_cstring).
+    ///
+    /// ```rust
+    /// use std::ffi::CString;
+    ///
+    /// use unixstring::UnixString;
+    ///
+    /// let unx = UnixString::from_string("/home/user".into()).unwrap();
+    ///
+    /// assert_eq!(unx.to_c_string(), CString::new("/home/user").unwrap());
+    /// ```
+    pub fn to_c_string(&self) -> CString {
+        self.as_c_str().to_owned()
+    }
+
+    /// Gets the underlying byte view of this `UnixString` *including* the nul terminator.
+    ///
+    /// ```rust
+    /// use unixstring::UnixString;
+    ///
+    /// let bytes = b"abc\0".to_vec();
+    /// let unix_string = UnixString::from_bytes(bytes).unwrap();
+    ///
+    /// assert_eq!(
+    ///     unix_string.as_bytes_with_nul(),
+    ///     &[b'a', b'b', b'c', 0]
+    /// );
+    pub fn as_bytes_with_nul(&self) -> &[u8] {
+        &self.inner
+    }
+
+    /// Returns the inner representation of a `UnixString`.
+    ///
+    /// The `UnixString`'s nul terminator byte will be included.
+    pub fn into_bytes_with_nul(self) -> Vec<u8> {
+        self.inner
+    }
+
+    /// Returns the buffer backing this `UnixString`, as it is.
+    ///
+    /// The nul terminator byte is included and the capacity of the buffer is preserved, so no copy or
+    /// reallocation ever happens. This makes it suitable for reusing the allocation as a scratch buffer.
+    ///
+    /// This is the same as [`UnixString::into_bytes_with_nul`](UnixString::into_bytes_with_nul).
+    ///
+    /// ```rust
+    /// use unixstring::UnixString;
+    ///
+    /// let mut unx = UnixString::with_capacity(64);
+    /// unx.push_bytes(b"abc").unwrap();
+    ///
+    /// let buf = unx.into_inner();
+    ///
+    /// assert_eq!(buf, b"abc\0");
+    /// assert!(buf.capacity() >= 65);
+    /// ```
+    pub fn into_inner(self) -> Vec<u8> {
+        self.inner
+    }
+
+    /// Returns the inner representation of a `UnixString` with its nul-terminator removed.
+    pub fn into_bytes(self) -> Vec<u8> {
+        let mut bytes = self.inner;
+        bytes.remove(bytes.len() - 1);
+        bytes
+    }
+
+    /// Converts this `UnixString` into a boxed slice of bytes, *including* its nul terminator.
+    ///
+    /// Any excess capacity of the `UnixString` is dropped.
+    pub fn into_boxed_bytes(self) -> Box<[u8]> {
+        self.inner.into_boxed_slice()
+    }
+
+    /// Converts this `UnixString` into a [`Box`]`<`[`CStr`]`>`.
+    ///
+    /// Any excess capacity of the `UnixString` is dropped.
+    ///
+    /// ```rust
+    /// use unixstring::UnixString;
+    ///
+    /// let unx = UnixString::from_string("/usr/lib".into()).unwrap();
+    /// let boxed = unx.clone().into_boxed_c_str();
+    ///
+    /// assert_eq!(&*boxed, unx.as_c_str());
+    /// ```
+    pub fn into_boxed_c_str(self) -> Box<CStr> {
+        // Safety: a UnixString always has a single zero byte, at its last position
+        unsafe { CString::from_vec_with_nul_unchecked(self.inner) }.into_boxed_c_str()
+    }
+
+    /// Converts this `UnixString` into an [`Arc`]`<`[`CStr`]`>`.
+    ///
+    /// Any excess capacity of the `UnixString` is dropped.
+    ///
+    /// ```rust
+    /// use std::sync::Arc;
+    ///
+    /// use unixstring::UnixString;
+    ///
+    /// let unx = UnixString::from_string("/usr/lib".into()).unwrap();
+    /// let shared = unx.clone().into_arc_c_str();
+    /// let other = Arc::clone(&shared);
+    ///
+    /// assert_eq!(&*other, unx.as_c_str());
+    /// ```
+    pub fn into_arc_c_str(self) -> Arc<CStr> {
+        Arc::from(self.into_boxed_c_str())
+    }
+
+    /// Converts this `UnixString` into an [`Rc`]`<`[`CStr`]`>`.
+    ///
+    /// Any excess capacity of the `UnixString` is dropped.
+    ///
+    /// ```rust
+    /// use unixstring::UnixString;
+    ///
+    /// let unx = UnixString::from_string("/usr/lib".into()).unwrap();
+    /// let shared = unx.clone().into_rc_c_str();
+    ///
+    /// assert_eq!(&*shared, unx.as_c_str());
+    /// ```
+    pub fn into_rc_c_str(self) -> Rc<CStr> {
+        Rc::from(self.into_boxed_c_str())
+    }
+
+    /// Converts a `CString` into an `UnixString`.
+    ///
+    /// This operation is zero-cost and does not fail.
+    ///
+    /// This operation fails if the `CString` has any interior zero byte but a zero byte at the last position is acceptable.
+    pub fn from_cstring(cstring: CString) -> Self {
+        cstring.into()
+    }
+
+    /// Converts a `PathBuf` into an `UnixString`.
+    ///
+    /// A validation will be done to ensure that the `PathBuf` has no interior zero bytes.
+    /// Other than that, this operation is zero-cost.
+    ///
+    /// This operation fails if the `PathBuf` has any interior zero byte but a zero byte at the last position is acceptable.
+    #[cfg(all(feature = "std", unix))]
+    pub fn from_pathbuf(pathbuf: PathBuf) -> Result<Self> {
+        pathbuf.try_into()
+    }
+
+    /// Converts a `String` into an `UnixString`.
+    ///
+    /// A validation will be done to ensure that the `String` has no interior zero bytes.
+    /// Other than that, this operation is zero-cost.
+    ///
+    /// This operation fails if the `String` has any interior zero byte but a zero byte at the last position is acceptable.
+    pub fn from_string(string: String) -> Result<Self> {
+        string.try_into()
+    }
+
+    /// Converts an `OsString` into an `UnixString`.
+    ///
+    /// A validation will be done to ensure that the `OsString` has no interior zero bytes.
+    /// Other than that, this operation is zero-cost.
+    ///
+    /// This operation fails if the `OsString` has any interior zero byte but a zero byte at the last position is acceptable.
+    #[cfg(all(feature = "std", unix))]
+    pub fn from_os_string(os_string: OsString) -> Result<Self> {
+        os_string.try_into()
+    }
+
+    /// Creates a `UnixString` by copying the bytes of the given `OsStr`.
+    ///
+    /// This is the borrowed counterpart of [`UnixString::from_os_string`](UnixString::from_os_string).
+    ///
+    /// This operation fails if the `OsStr` has any interior zero byte but a zero byte at the last position is acceptable.
+    ///
+    /// ```rust
+    /// use std::ffi::OsStr;
+    ///
+    /// use unixstring::UnixString;
+    ///
+    /// let unx = UnixString::from_os_str(OsStr::new("/home/user")).unwrap();
+    /// assert_eq!(unx.as_os_str(), "/home/user");
+    /// ```
+    #[cfg(all(feature = "std", unix))]
+    pub fn from_os_str(os_str: &OsStr) -> Result<Self> {
+        os_str.as_bytes().try_into()
+    }
+
+    /// Creates a `UnixString` by copying the bytes of the given `Path`.
+    ///
+    /// This is the borrowed counterpart of [`UnixString::from_pathbuf`](UnixString::from_pathbuf).
+    ///
+    /// This operation fails if the `Path` has any interior zero byte but a zero byte at the last position is acceptable.
+    ///
+    /// ```rust
+    /// use std::path::Path;
+    ///
+    /// use unixstring::UnixString;
+    ///
+    /// let unx = UnixString::from_path(Path::new("/home/user")).unwrap();
+    /// assert_eq!(unx.as_path(), Path::new("/home/user"));
+    /// ```
+    #[cfg(all(feature = "std", unix))]
+    pub fn from_path(path: &Path) -> Result<Self> {
+        Self::from_os_str(path.as_os_str())
+    }
+
+    /// Checks if the `UnixString` starts with the given slice.
+    ///
+    /// ```
+    /// use unixstring::UnixString;
+    /// # use unixstring::Result;
+    /// # fn main() -> Result<()> {
+    /// let mut unix_string = UnixString::new();
+    /// unix_string.push("/home/")?;
+    /// unix_string.push("user")?;
+    ///
+    /// assert!(unix_string.starts_with("/home"));
+    /// assert!(unix_string.starts_with("/home/user"));
+    /// assert!(!unix_string.starts_with("/home/user/"));
+    /// assert!(!unix_string.starts_with("/home/other-user"));
+    ///
+    ///
+    /// # Ok(()) }
+    /// ```
+    #[cfg(all(feature = "std", unix))]
+    pub fn starts_with(&self, rhs: impl AsRef<OsStr>) -> bool {
+        let rhs = rhs.as_ref().as_bytes();
+        match self.as_bytes().get(0..rhs.len()) {
+            Some(subslice) => subslice == rhs,
+            None => false,
+        }
+    }
+
+    /// Returns the bytes of this `UnixString` after `prefix`, if it starts with it.
+    ///
+    /// The comparison is done byte by byte, so it works for non-UTF-8 content as well.
+    /// The nul terminator is never included in the returned slice.
+    ///
+    /// ```
+    /// use unixstring::UnixString;
+    /// # use unixstring::Result;
+    /// # fn main() -> Result<()> {
+    /// let unix_string = UnixString::from_string("/home/user/.bashrc".into())?;
+    ///
+    /// assert_eq!(unix_string.strip_prefix("/home/user/"), Some(&b".bashrc"[..]));
+    /// assert_eq!(unix_string.strip_prefix("/root/"), None);
+    /// # Ok(()) }
+    /// ```
+    #[cfg(all(feature = "std", unix))]
+    pub fn strip_prefix(&self, prefix: impl AsRef<OsStr>) -> Option<&[u8]> {
+        self.as_bytes().strip_prefix(prefix.as_ref().as_bytes())
+    }
+
+    /// Checks if the `UnixString` ends with the given slice.
+    ///
+    /// The comparison is done byte-wise and never considers the nul terminator.
+    ///
+    /// ```
+    /// use unixstring::UnixString;
+    /// # use unixstring::Result;
+    /// # fn main() -> Result<()> {
+    /// let mut unix_string = UnixString::new();
+    /// unix_string.push("/home/")?;
+    /// unix_string.push("user")?;
+    ///
+    /// assert!(unix_string.ends_with("user"));
+    /// assert!(unix_string.ends_with("/home/user"));
+    /// assert!(!unix_string.ends_with("/home/user/"));
+    /// assert!(!unix_string.ends_with("other-user"));
+    ///
+    /// # Ok(()) }
+    /// ```
+    #[cfg(all(feature = "std", unix))]
+    pub fn ends_with(&self, rhs: impl AsRef<OsStr>) -> bool {
+        self.as_bytes().ends_with(rhs.as_ref().as_bytes())
+    }
+
+    /// Returns the bytes of this `UnixString` before `suffix`, if it ends with it.
+    ///
+    /// The comparison is done byte by byte, so it works for non-UTF-8 content as well.
+    /// The nul terminator is never included in the returned slice nor matched by `suffix`.
+    ///
+    /// ```
+    /// use unixstring::UnixString;
+    /// # use unixstring::Result;
+    /// # fn main() -> Result<()> {
+    /// let unix_string = UnixString::from_string("archive.tar.gz".into())?;
+    ///
+    /// assert_eq!(unix_string.strip_suffix(".gz"), Some(&b"archive.tar"[..]));
+    /// assert_eq!(unix_string.strip_suffix(""), Some(&b"archive.tar.gz"[..]));
+    /// assert_eq!(unix_string.strip_suffix(".xz"), None);
+    /// # Ok(()) }
+    /// ```
+    #[cfg(all(feature = "std", unix))]
+    pub fn strip_suffix(&self, suffix: impl AsRef<OsStr>) -> Option<&[u8]> {
+        self.as_bytes().strip_suffix(suffix.as_ref().as_bytes())
+    }
 
-    /// Extends a `UnixString` by copying from a raw C string
+    /// Returns the byte index of the first occurrence of `needle` in this `UnixString`, if any.
     ///
-    /// # Safety
+    /// The search is done over the bytes of the `UnixString` *without* its nul terminator, so it will never be matched.
+    /// An empty `needle` always matches at index 0.
     ///
-    /// This method is unsafe for a number of reasons:
-    /// * The total size of the raw C string must be smaller than `isize::MAX` bytes in memory.
-    /// * There is no guarantee to the validity of `ptr`.
-    /// * There is no guarantee that the memory pointed to by `ptr` contains a
-    ///   valid nul terminator byte at the end of the string.
-    /// * It is not guaranteed that the memory pointed by `ptr` won't change
-    ///   while this operation occurs.
+    /// ```
+    /// use unixstring::UnixString;
+    /// # use unixstring::Result;
+    /// # fn main() -> Result<()> {
+    /// let unix_string = UnixString::from_string("/bin:/usr/bin".into())?;
     ///
-    /// This function uses [`CStr::from_ptr`](std::ffi::CStr::from_ptr) internally, so check it out for more information.
+    /// assert_eq!(unix_string.find("/usr/"), Some(5));
+    /// assert_eq!(unix_string.find(b":"), Some(4));
+    /// assert_eq!(unix_string.find("/sbin"), None);
+    /// assert_eq!(unix_string.find(""), Some(0));
     ///
-    pub unsafe fn extend_from_ptr(&mut self, ptr: *const libc::c_char) -> Result<()> {
-        let cstr = CStr::from_ptr(ptr);
-        let bytes = cstr.to_bytes();
-        self.push_bytes(bytes)
+    /// # Ok(()) }
+    /// ```
+    pub fn find(&self, needle: impl AsRef<[u8]>) -> Option<usize> {
+        find_subslice(self.as_bytes(), needle.as_ref())
     }
 
-    /// This function will check if the inner bytes of this `UnixString` (without its null terminator) consists of valid UTF-8, and if so, returns a `String` reusing the `UnixString`'s buffer.
+    /// Creates a new `UnixString` with all non-overlapping occurrences of `from` replaced by `to`.
     ///
-    /// If the inner bytes contain invalid UTF-8, then a new `String` will be allocated with the invalid bytes replaced with the Unicode replacement codepoint.
-    pub fn into_string_lossy(self) -> String {
-        let bytes = self.into_bytes();
-        match String::from_utf8_lossy(&bytes) {
-            Cow::Owned(new_string) => new_string,
-            Cow::Borrowed(_) => {
-                // Safety: String::from_utf8_lossy returns a reference when the supplied input
-                // is valid UTF-8. Constructing a String unchecked, therefore, is safe.
-                unsafe { String::from_utf8_unchecked(bytes) }
-            }
+    /// Occurrences are found from left to right, as with [`UnixString::find`](UnixString::find).
+    /// An empty `from` matches nothing, so the returned `UnixString` is a copy of `self`.
+    ///
+    /// Since `to` is placed in the middle of the string, it can't contain a zero byte at all,
+    /// otherwise [`Error::InteriorNulByte`] is returned.
+    ///
+    /// ```
+    /// use unixstring::UnixString;
+    /// # use unixstring::Result;
+    /// # fn main() -> Result<()> {
+    /// let unix_string = UnixString::from_string("/mnt/old/a:/mnt/old/b".into())?;
+    ///
+    /// let replaced = unix_string.replace_bytes(b"/mnt/old", b"/media/new")?;
+    ///
+    /// assert_eq!(replaced.to_str()?, "/media/new/a:/media/new/b");
+    /// # Ok(()) }
+    /// ```
+    pub fn replace_bytes(&self, from: &[u8], to: &[u8]) -> Result<UnixString> {
+        if let Some(nul_pos) = find_nul_byte(to) {
+            return Err(Error::InteriorNulByte { position: nul_pos });
+        }
+
+        if from.is_empty() {
+            return Ok(self.clone());
+        }
+
+        let mut remaining = self.as_bytes();
+        let mut inner = Vec::with_capacity(self.inner.len());
+        while let Some(pos) = find_subslice(remaining, from) {
+            inner.extend_from_slice(&remaining[..pos]);
+            inner.extend_from_slice(to);
+            remaining = &remaining[pos + from.len()..];
         }
+        inner.extend_from_slice(remaining);
+        inner.push(b'\0');
+
+        Ok(Self { inner })
     }
 
-    /// Converts a `UnixString` into a String if the bytes of the `UnixString` are valid UTF-8.
+    /// Checks if `needle` occurs anywhere in this `UnixString`.
     ///
-    /// If you are sure that the byte slice is valid UTF-8 and you don’t want to incur the overhead of the validity check, there is an unsafe version of this function, [`UnixString::into_string_unchecked`](UnixString::into_string_unchecked), which has the same behavior but skips the check.
+    /// See [`UnixString::find`](UnixString::find) for more info.
     ///
-    /// If the validity check passes, the resulting `String` will reuse the allocation of the `UnixString`'s inner buffer and no copy will be done.
+    /// ```
+    /// use unixstring::UnixString;
+    /// # use unixstring::Result;
+    /// # fn main() -> Result<()> {
+    /// let unix_string = UnixString::from_string("/bin:/usr/bin".into())?;
     ///
-    /// If you need a `&str` instead of a `String`, consider [`UnixString::as_str`](UnixString::to_str).
-    pub fn into_string(self) -> Result<String> {
-        Ok(String::from_utf8(self.into_bytes())?)
+    /// assert!(unix_string.contains("/usr/"));
+    /// assert!(!unix_string.contains("/sbin"));
+    ///
+    /// # Ok(()) }
+    /// ```
+    pub fn contains(&self, needle: impl AsRef<[u8]>) -> bool {
+        self.find(needle).is_some()
     }
 
-    /// Converts a `UnixString` into a `String` without checking that the
-    /// string contains valid UTF-8.
+    /// Returns an iterator over the starting indices of all non-overlapping occurrences of `needle` in this `UnixString`.
     ///
-    /// See the safe version, [`UnixString::into_string`](UnixString::into_string), for more details.
+    /// Occurrences are found from left to right, as with [`UnixString::find`](UnixString::find), and the nul terminator
+    /// is never matched. An empty `needle` matches nothing, so nothing is yielded.
     ///
-    /// # Safety
+    /// ```
+    /// use unixstring::UnixString;
+    /// # use unixstring::Result;
+    /// # fn main() -> Result<()> {
+    /// let unix_string = UnixString::from_string("/usr/local/lib".into())?;
     ///
-    /// This function is unsafe because it does not check that the bytes passed
-    /// to it are valid UTF-8. If this constraint is violated, it may cause
-    /// memory unsafety issues with future users of the `String`, as the rest of
-    /// the standard library assumes that `String`s are valid UTF-8.
+    /// assert_eq!(unix_string.match_indices(b"/").collect::<Vec<_>>(), [0, 4, 10]);
+    /// assert_eq!(unix_string.match_indices(b"l").count(), 3);
+    /// # Ok(()) }
+    /// ```
+    pub fn match_indices<'a>(&'a self, needle: &'a [u8]) -> impl Iterator<Item = usize> + 'a {
+        let haystack = self.as_bytes();
+        let mut offset = 0;
+
+        core::iter::from_fn(move || {
+            if needle.is_empty() {
+                return None;
+            }
+
+            let pos = offset + find_subslice(&haystack[offset..], needle)?;
+            offset = pos + needle.len();
+
+            Some(pos)
+        })
+    }
+
+    /// Counts how many times `byte` appears in this `UnixString`, not considering its nul terminator.
     ///
-    /// # Examples
+    /// This is not named `count_bytes` since that would shadow [`CStr::count_bytes`](core::ffi::CStr::count_bytes),
+    /// reachable through `Deref`, which has a different meaning: it returns the length of the string. For that, use
+    /// [`UnixString::len`](UnixString::len) instead.
     ///
     /// ```
     /// use unixstring::UnixString;
+    /// # use unixstring::Result;
+    /// # fn main() -> Result<()> {
+    /// let unix_string = UnixString::from_string("/usr/local/lib".into())?;
     ///
-    /// let baby = UnixString::from_bytes(vec![0xF0, 0x9F, 0x91, 0xB6]).unwrap();
+    /// assert_eq!(unix_string.count_byte(b'/'), 3);
+    /// assert_eq!(unix_string.count_byte(b'x'), 0);
+    /// assert_eq!(unix_string.count_byte(0), 0);
+    /// # Ok(()) }
+    /// ```
+    pub fn count_byte(&self, byte: u8) -> usize {
+        let mut haystack = self.as_bytes();
+        let mut count = 0;
+
+        while let Some(pos) = memchr(byte, haystack) {
+            count += 1;
+            haystack = &haystack[pos + 1..];
+        }
+
+        count
+    }
+
+    /// Divides the bytes of this `UnixString` into two slices at the given index.
     ///
-    /// let baby = unsafe {
-    ///     baby.into_string_unchecked()
-    /// };
+    /// The first slice contains the bytes in `[0, mid)` and the second one the bytes in `[mid, len)`.
+    /// The nul terminator is never included in either slice.
     ///
-    /// assert_eq!("👶", baby);
+    /// # Panics
+    ///
+    /// Panics if `mid > self.len()`.
+    ///
+    /// ```rust
+    /// use unixstring::UnixString;
+    ///
+    /// let unx = UnixString::from_string("/home/user".into()).unwrap();
+    ///
+    /// assert_eq!(unx.split_at(5), (&b"/home"[..], &b"/user"[..]));
     /// ```
-    pub unsafe fn into_string_unchecked(self) -> String {
-        String::from_utf8_unchecked(self.into_bytes())
+    pub fn split_at(&self, mid: usize) -> (&[u8], &[u8]) {
+        self.as_bytes().split_at(mid)
     }
 
-    /// This function will check if the inner bytes of this `UnixString` (without its null terminator) consists of valid UTF-8, and if so, returns a string slice without copying.
+    /// Returns the bytes of this `UnixString` within the given range, or `None` if it's out of bounds.
     ///
-    /// If the inner bytes contain invalid UTF-8, then a new `String` will be allocated with the invalid bytes replaced with the Unicode replacement codepoint.
-    pub fn to_string_lossy(&self) -> Cow<str> {
-        self.as_c_str().to_string_lossy()
+    /// The range must lie within `0..=self.len()`, so the nul terminator is never included.
+    /// This is the non-panicking counterpart of indexing a `UnixString` with a range.
+    ///
+    /// ```rust
+    /// use unixstring::UnixString;
+    ///
+    /// let unx = UnixString::from_string("/home/user".into()).unwrap();
+    ///
+    /// assert_eq!(unx.get(1..5), Some(&b"home"[..]));
+    /// assert_eq!(unx.get(6..), Some(&b"user"[..]));
+    /// assert_eq!(unx.get(..=unx.len()), None);
+    /// ```
+    pub fn get(&self, range: impl RangeBounds<usize>) -> Option<&[u8]> {
+        let bytes = self.as_bytes();
+        let range = resolve_range(range, bytes.len())?;
+
+        Some(&bytes[range])
     }
 
-    /// Gets the underlying byte view of this `UnixString` *without* the nul terminator.
-    /// ```rust
+    /// Returns an iterator over the slices of this `UnixString` separated by `delimiter`.
+    ///
+    /// The nul terminator is never included in the yielded slices. This follows the semantics of
+    /// [`slice::split`]: consecutive delimiters yield empty slices and an empty `UnixString` yields a single empty slice.
+    ///
+    /// ```
+    /// use unixstring::UnixString;
+    /// # use unixstring::Result;
+    /// # fn main() -> Result<()> {
+    /// let unix_string = UnixString::from_string("/bin::/usr/bin".into())?;
+    ///
+    /// let mut split = unix_string.split(b':');
+    ///
+    /// assert_eq!(split.next(), Some(&b"/bin"[..]));
+    /// assert_eq!(split.next(), Some(&b""[..]));
+    /// assert_eq!(split.next(), Some(&b"/usr/bin"[..]));
+    /// assert_eq!(split.next(), None);
+    ///
+    /// # Ok(()) }
+    /// ```
+    pub fn split(&self, delimiter: u8) -> impl Iterator<Item = &[u8]> + '_ {
+        let mut remaining = Some(self.as_bytes());
+
+        core::iter::from_fn(move || {
+            let bytes = remaining?;
+            match memchr(delimiter, bytes) {
+                Some(pos) => {
+                    remaining = Some(&bytes[pos + 1..]);
+                    Some(&bytes[..pos])
+                }
+                None => {
+                    remaining = None;
+                    Some(bytes)
+                }
+            }
+        })
+    }
+
+    /// Checks that this `UnixString` and the given bytes are an ASCII case-insensitive match.
+    ///
+    /// Non-ASCII bytes are compared exactly and the nul terminator is not considered.
+    ///
+    /// See also: [`slice::eq_ignore_ascii_case`].
+    ///
+    /// ```
     /// use unixstring::UnixString;
+    /// # use unixstring::Result;
+    /// # fn main() -> Result<()> {
+    /// let unix_string = UnixString::from_string("README.md".into())?;
     ///
-    /// let bytes = b"abc\0".to_vec();
-    /// let unix_string = UnixString::from_bytes(bytes).unwrap();
+    /// assert!(unix_string.eq_ignore_ascii_case("readme.MD"));
+    /// assert!(!unix_string.eq_ignore_ascii_case("readme"));
     ///
-    /// assert_eq!(
-    ///     unix_string.as_bytes(),
-    ///     &[b'a', b'b', b'c']
-    /// );
-    pub fn as_bytes(&self) -> &[u8] {
-        self.inner_without_nul_terminator()
+    /// # Ok(()) }
+    /// ```
+    pub fn eq_ignore_ascii_case(&self, other: impl AsRef<[u8]>) -> bool {
+        self.as_bytes().eq_ignore_ascii_case(other.as_ref())
     }
 
-    /// Converts a `UnixString` into an [`OsString`].
+    /// Converts this `UnixString` to its ASCII lower case equivalent in-place.
     ///
-    /// This operation is zero-cost.
+    /// ASCII letters 'A' to 'Z' are mapped to 'a' to 'z', but non-ASCII letters are unchanged.
+    /// The nul terminator is never touched.
     ///
-    /// If you need a `&OsStr` instead of an `OsString`, consider [`UnixString::as_os_str`](UnixString::as_os_str).
-    pub fn into_os_string(self) -> OsString {
-        self.into()
+    /// To return a new lowercased value without modifying the existing one, use [`UnixString::to_ascii_lowercase`](UnixString::to_ascii_lowercase).
+    pub fn make_ascii_lowercase(&mut self) {
+        let len = self.len();
+        self.inner[..len].make_ascii_lowercase()
     }
 
-    /// Converts a `UnixString` into a [`PathBuf`].
+    /// Converts this `UnixString` to its ASCII upper case equivalent in-place.
     ///
-    /// This operation is zero-cost.
+    /// ASCII letters 'a' to 'z' are mapped to 'A' to 'Z', but non-ASCII letters are unchanged.
+    /// The nul terminator is never touched.
     ///
-    /// If you need a `&Path` instead of a `PathBuf`, consider [`UnixString::as_path`](UnixString::as_path).
-    pub fn into_pathbuf(self) -> PathBuf {
-        self.into()
+    /// To return a new uppercased value without modifying the existing one, use [`UnixString::to_ascii_uppercase`](UnixString::to_ascii_uppercase).
+    pub fn make_ascii_uppercase(&mut self) {
+        let len = self.len();
+        self.inner[..len].make_ascii_uppercase()
     }
 
-    /// Converts a `UnixString` into a [`PathBuf`].
+    /// Returns a copy of this `UnixString` where each byte is mapped to its ASCII lower case equivalent.
     ///
-    /// This operation is zero-cost.
+    /// ```
+    /// use unixstring::UnixString;
+    /// # use unixstring::Result;
+    /// # fn main() -> Result<()> {
+    /// let unix_string = UnixString::from_string("IMAGE.PNG".into())?;
     ///
-    /// If you need a `&CStr` instead of a `CString`, consider [`UnixString::as_c_str`](UnixString::as_c_str).
-    pub fn into_cstring(self) -> CString {
-        self.into()
+    /// assert_eq!(unix_string.to_ascii_lowercase().to_str()?, "image.png");
+    ///
+    /// # Ok(()) }
+    /// ```
+    pub fn to_ascii_lowercase(&self) -> UnixString {
+        let mut unx = self.clone();
+        unx.make_ascii_lowercase();
+        unx
     }
 
-    /// Gets the underlying byte view of this `UnixString` *including* the nul terminator.
+    /// Returns a copy of this `UnixString` where each byte is mapped to its ASCII upper case equivalent.
     ///
-    /// ```rust
+    /// ```
     /// use unixstring::UnixString;
+    /// # use unixstring::Result;
+    /// # fn main() -> Result<()> {
+    /// let unix_string = UnixString::from_string("image.png".into())?;
     ///
-    /// let bytes = b"abc\0".to_vec();
-    /// let unix_string = UnixString::from_bytes(bytes).unwrap();
+    /// assert_eq!(unix_string.to_ascii_uppercase().to_str()?, "IMAGE.PNG");
     ///
-    /// assert_eq!(
-    ///     unix_string.as_bytes_with_nul(),
-    ///     &[b'a', b'b', b'c', 0]
-    /// );
-    pub fn as_bytes_with_nul(&self) -> &[u8] {
-        &self.inner
+    /// # Ok(()) }
+    /// ```
+    pub fn to_ascii_uppercase(&self) -> UnixString {
+        let mut unx = self.clone();
+        unx.make_ascii_uppercase();
+        unx
     }
 
-    /// Returns the inner representation of a `UnixString`.
+    /// Returns the byte index of the last occurrence of `needle` in this `UnixString`, if any.
     ///
-    /// The `UnixString`'s nul terminator byte will be included.
-    pub fn into_bytes_with_nul(self) -> Vec<u8> {
-        self.inner
+    /// The search is done over the bytes of the `UnixString` *without* its nul terminator, so it will never be matched.
+    ///
+    /// ```
+    /// use unixstring::UnixString;
+    /// # use unixstring::Result;
+    /// # fn main() -> Result<()> {
+    /// let unix_string = UnixString::from_string("archive.tar.gz".into())?;
+    ///
+    /// assert_eq!(unix_string.rfind(b'.'), Some(11));
+    /// assert_eq!(unix_string.rfind(b'/'), None);
+    ///
+    /// # Ok(()) }
+    /// ```
+    pub fn rfind(&self, needle: u8) -> Option<usize> {
+        memrchr(needle, self.as_bytes())
     }
 
-    /// Returns the inner representation of a `UnixString` with its nul-terminator removed.
-    pub fn into_bytes(self) -> Vec<u8> {
-        let mut bytes = self.inner;
-        bytes.remove(bytes.len() - 1);
-        bytes
+    /// Returns an iterator over the slices of this `UnixString` separated by `delimiter`, starting from its end.
+    ///
+    /// The nul terminator is never included in the yielded slices. This follows the semantics of [`slice::rsplit`].
+    ///
+    /// ```
+    /// use unixstring::UnixString;
+    /// # use unixstring::Result;
+    /// # fn main() -> Result<()> {
+    /// let unix_string = UnixString::from_string("archive.tar.gz".into())?;
+    ///
+    /// let mut rsplit = unix_string.rsplit(b'.');
+    ///
+    /// assert_eq!(rsplit.next(), Some(&b"gz"[..]));
+    /// assert_eq!(rsplit.next(), Some(&b"tar"[..]));
+    /// assert_eq!(rsplit.next(), Some(&b"archive"[..]));
+    /// assert_eq!(rsplit.next(), None);
+    ///
+    /// # Ok(()) }
+    /// ```
+    pub fn rsplit(&self, delimiter: u8) -> impl Iterator<Item = &[u8]> + '_ {
+        let mut remaining = Some(self.as_bytes());
+
+        core::iter::from_fn(move || {
+            let bytes = remaining?;
+            match memrchr(delimiter, bytes) {
+                Some(pos) => {
+                    remaining = Some(&bytes[..pos]);
+                    Some(&bytes[pos + 1..])
+                }
+                None => {
+                    remaining = None;
+                    Some(bytes)
+                }
+            }
+        })
     }
 
-    /// Converts a `CString` into an `UnixString`.
+    /// Splits this `UnixString` at the first occurrence of `delimiter`, returning the bytes before and after it.
     ///
-    /// This operation is zero-cost and does not fail.
+    /// Neither slice includes the delimiter nor the nul terminator. Returns `None` if `delimiter` is not found.
+    /// This mirrors [`str::split_once`].
     ///
-    /// This operation fails if the `CString` has any interior zero byte but a zero byte at the last position is acceptable.
-    pub fn from_cstring(cstring: CString) -> Self {
-        cstring.into()
+    /// ```
+    /// use unixstring::UnixString;
+    /// # use unixstring::Result;
+    /// # fn main() -> Result<()> {
+    /// let unix_string = UnixString::from_string("PATH=/bin:/usr/bin".into())?;
+    ///
+    /// assert_eq!(unix_string.split_once(b'='), Some((&b"PATH"[..], &b"/bin:/usr/bin"[..])));
+    /// assert_eq!(unix_string.split_once(b'#'), None);
+    /// # Ok(()) }
+    /// ```
+    pub fn split_once(&self, delimiter: u8) -> Option<(&[u8], &[u8])> {
+        let bytes = self.as_bytes();
+        let pos = memchr(delimiter, bytes)?;
+
+        Some((&bytes[..pos], &bytes[pos + 1..]))
     }
 
-    /// Converts a `PathBuf` into an `UnixString`.
+    /// Splits this `UnixString` at the last occurrence of `delimiter`, returning the bytes before and after it.
     ///
-    /// A validation will be done to ensure that the `PathBuf` has no interior zero bytes.
-    /// Other than that, this operation is zero-cost.
+    /// Neither slice includes the delimiter nor the nul terminator. Returns `None` if `delimiter` is not found.
+    /// This mirrors [`str::rsplit_once`].
     ///
-    /// This operation fails if the `PathBuf` has any interior zero byte but a zero byte at the last position is acceptable.
-    pub fn from_pathbuf(pathbuf: PathBuf) -> Result<Self> {
-        pathbuf.try_into()
+    /// ```
+    /// use unixstring::UnixString;
+    /// # use unixstring::Result;
+    /// # fn main() -> Result<()> {
+    /// let unix_string = UnixString::from_string("archive.tar.gz".into())?;
+    ///
+    /// assert_eq!(unix_string.rsplit_once(b'.'), Some((&b"archive.tar"[..], &b"gz"[..])));
+    /// assert_eq!(unix_string.rsplit_once(b'/'), None);
+    /// # Ok(()) }
+    /// ```
+    pub fn rsplit_once(&self, delimiter: u8) -> Option<(&[u8], &[u8])> {
+        let bytes = self.as_bytes();
+        let pos = memrchr(delimiter, bytes)?;
+
+        Some((&bytes[..pos], &bytes[pos + 1..]))
     }
 
-    /// Converts a `String` into an `UnixString`.
+    /// Returns the bytes of this `UnixString` with all trailing occurrences of `byte` removed.
     ///
-    /// A validation will be done to ensure that the `String` has no interior zero bytes.
-    /// Other than that, this operation is zero-cost.
+    /// The nul terminator is never included in the returned slice.
+    ///
+    /// ```rust
+    /// use unixstring::UnixString;
+    ///
+    /// let unx = UnixString::from_string("/usr/lib//".into()).unwrap();
     ///
-    /// This operation fails if the `String` has any interior zero byte but a zero byte at the last position is acceptable.
-    pub fn from_string(string: String) -> Result<Self> {
-        string.try_into()
+    /// assert_eq!(unx.trimmed_end(b'/'), b"/usr/lib");
+    /// ```
+    pub fn trimmed_end(&self, byte: u8) -> &[u8] {
+        let bytes = self.as_bytes();
+        let len = bytes
+            .iter()
+            .rposition(|&other| other != byte)
+            .map_or(0, |pos| pos + 1);
+
+        &bytes[..len]
     }
 
-    /// Converts an `OsString` into an `UnixString`.
+    /// Removes all trailing occurrences of `byte` from this `UnixString`.
     ///
-    /// A validation will be done to ensure that the `OsString` has no interior zero bytes.
-    /// Other than that, this operation is zero-cost.
+    /// If the `UnixString` is made up only of `byte`, it'll be left empty.
     ///
-    /// This operation fails if the `OsString` has any interior zero byte but a zero byte at the last position is acceptable.
-    pub fn from_os_string(os_string: OsString) -> Result<Self> {
-        os_string.try_into()
+    /// ```rust
+    /// use unixstring::UnixString;
+    ///
+    /// let mut unx = UnixString::from_string("/usr/lib//".into()).unwrap();
+    /// unx.trim_end_matches(b'/');
+    ///
+    /// assert_eq!(unx.as_bytes_with_nul(), b"/usr/lib\0");
+    /// ```
+    pub fn trim_end_matches(&mut self, byte: u8) {
+        let len = self.trimmed_end(byte).len();
+        self.inner.truncate(len);
+        self.inner.push(b'\0');
     }
 
-    /// Checks if the `UnixString` starts with the given slice.
+    /// Creates a new `UnixString` by repeating the bytes of this one `n` times.
     ///
-    /// ```
-    /// use unixstring::UnixString;
-    /// # use unixstring::Result;
-    /// # fn main() -> Result<()> {
-    /// let mut unix_string = UnixString::new();
-    /// unix_string.push("/home/")?;
-    /// unix_string.push("user")?;
+    /// If `n` is zero, the returned `UnixString` is empty.
     ///
-    /// assert!(unix_string.starts_with("/home"));
-    /// assert!(unix_string.starts_with("/home/user"));
-    /// assert!(!unix_string.starts_with("/home/user/"));
-    /// assert!(!unix_string.starts_with("/home/other-user"));
+    /// # Panics
     ///
+    /// Panics if the capacity of the new `UnixString` would overflow.
     ///
-    /// # Ok(()) }
+    /// ```rust
+    /// use unixstring::UnixString;
+    ///
+    /// let unx = UnixString::from_string("ab".into()).unwrap();
+    ///
+    /// assert_eq!(unx.repeat(3).as_bytes_with_nul(), b"ababab\0");
+    /// assert!(unx.repeat(0).is_empty());
     /// ```
-    pub fn starts_with(&self, rhs: impl AsRef<OsStr>) -> bool {
-        let rhs = rhs.as_ref().as_bytes();
-        match self.as_bytes().get(0..rhs.len()) {
-            Some(subslice) => subslice == rhs,
-            None => false,
-        }
+    pub fn repeat(&self, n: usize) -> UnixString {
+        let mut inner = self.as_bytes().repeat(n);
+        inner.push(b'\0');
+
+        Self { inner }
     }
 
     /// Returns an unsafe mutable pointer to the `UnixString`'s buffer.
@@ -493,9 +2285,9 @@ impl UnixString {
     ///
     /// * The caller must ensure that the `UnixString` remains valid after modification.
     /// * The caller must ensure that the `UnixString` outlives the pointer this
-    /// function returns, or else it ends up pointing to garbage.
+    ///   function returns, or else it ends up pointing to garbage.
     /// * Modifying the vector may cause its buffer to be reallocated,
-    /// which would also make any pointers to it invalid.
+    ///   which would also make any pointers to it invalid.
     ///
     /// If you want to ensure that your `UnixString` is still valid after modified through [`as_mut_ptr`](UnixString::as_mut_ptr),
     /// check out [`UnixString::validate`](UnixString::validate).
@@ -554,6 +2346,78 @@ impl UnixString {
         self.inner.set_len(new_len)
     }
 
+    /// Appends up to `required` bytes to this `UnixString` by letting `f` fill a buffer in place.
+    ///
+    /// This is a safe alternative to the [`as_mut_ptr`](UnixString::as_mut_ptr) and
+    /// [`set_len`](UnixString::set_len) pattern when the `UnixString` serves as a buffer for FFI calls.
+    ///
+    /// `f` receives a zero-initialized buffer of `required` bytes and must return how many of them it wrote.
+    /// The written bytes are then appended to `self`. A single nul terminator at the end of the written
+    /// bytes is accepted and discarded.
+    ///
+    /// If the written bytes contain an interior zero byte, [`Error::InteriorNulByte`] is returned and
+    /// `self` is left unchanged.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `f` returns a length greater than `required`. If `f` itself panics, the panic is propagated.
+    /// In both cases, `self` is left unchanged.
+    ///
+    /// ```rust
+    /// # use unixstring::Result;
+    /// # fn main() -> Result<()> {
+    /// use unixstring::UnixString;
+    ///
+    /// let mut unx = UnixString::from_bytes(b"/home/".to_vec())?;
+    ///
+    /// unx.with_mut_buffer(16, |buf| {
+    ///     // This mocks a potential FFI call that fills the buffer
+    ///     buf[..4].copy_from_slice(b"user");
+    ///     4
+    /// })?;
+    ///
+    /// assert_eq!(unx.as_bytes(), b"/home/user");
+    /// assert!(unx.validate().is_ok());
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn with_mut_buffer(
+        &mut self,
+        required: usize,
+        f: impl FnOnce(&mut [u8]) -> usize,
+    ) -> Result<()> {
+        let start = self.len();
+        let end = start
+            .checked_add(required)
+            .expect("buffer size overflows usize");
+
+        // Puts the terminator back even if `f` panics, so that `self` is never left with interior zero bytes
+        let mut guard = TerminateOnDrop {
+            inner: &mut self.inner,
+            len: start,
+        };
+        guard.inner.resize(end, 0);
+
+        let written = f(&mut guard.inner[start..end]);
+        assert!(
+            written <= required,
+            "with_mut_buffer: closure wrote {} bytes into a buffer of {} bytes",
+            written,
+            required
+        );
+
+        match strip_nul_terminator(&guard.inner[start..start + written]) {
+            Ok(content) => {
+                guard.len = start + content.len();
+                Ok(())
+            }
+            Err(Error::InteriorNulByte { position }) => Err(Error::InteriorNulByte {
+                position: start + position,
+            }),
+            Err(err) => Err(err),
+        }
+    }
+
     /// Returns the number of bytes this `UnixString` can hold without
     /// reallocating.
     ///
@@ -571,6 +2435,141 @@ impl UnixString {
         self.inner.capacity()
     }
 
+    /// Returns how many more bytes can be pushed into this `UnixString` without reallocating.
+    ///
+    /// This is `capacity() - len_with_nul()`: the nul terminator already occupies its byte, so it's not part of the
+    /// spare capacity. This is useful when writing directly into the spare capacity through
+    /// [`UnixString::as_mut_ptr`](UnixString::as_mut_ptr).
+    ///
+    /// ```rust
+    /// use unixstring::UnixString;
+    ///
+    /// let mut unx = UnixString::with_capacity(50);
+    /// assert_eq!(unx.spare_capacity(), 50);
+    ///
+    /// unx.push_bytes(b"/home").unwrap();
+    /// assert_eq!(unx.spare_capacity(), 45);
+    /// ```
+    pub fn spare_capacity(&self) -> usize {
+        self.capacity() - self.len_with_nul()
+    }
+
+    /// Reserves capacity for at least `additional` more bytes to be pushed into this `UnixString`.
+    ///
+    /// `additional` refers to content bytes: the nul terminator is already accounted for, so after
+    /// calling `reserve(n)` the capacity will be greater than or equal to `self.len_with_nul() + n`.
+    ///
+    /// See also: [`Vec::reserve`](std::vec::Vec::reserve).
+    ///
+    /// # Panics
+    ///
+    /// Panics if the new capacity exceeds `isize::MAX` bytes.
+    ///
+    /// ```rust
+    /// use unixstring::UnixString;
+    ///
+    /// let mut unx = UnixString::new();
+    /// unx.reserve(10);
+    ///
+    /// assert!(unx.capacity() >= unx.len_with_nul() + 10);
+    /// ```
+    pub fn reserve(&mut self, additional: usize) {
+        self.inner.reserve(additional)
+    }
+
+    /// Reserves the minimum capacity for exactly `additional` more bytes to be pushed into this `UnixString`.
+    ///
+    /// `additional` refers to content bytes: the nul terminator is already accounted for, so after
+    /// calling `reserve_exact(n)` the capacity will be greater than or equal to `self.len_with_nul() + n`.
+    ///
+    /// Note that the allocator may give the `UnixString` more space than it requests.
+    /// Prefer [`UnixString::reserve`](UnixString::reserve) if future insertions are expected.
+    ///
+    /// See also: [`Vec::reserve_exact`](std::vec::Vec::reserve_exact).
+    ///
+    /// # Panics
+    ///
+    /// Panics if the new capacity exceeds `isize::MAX` bytes.
+    pub fn reserve_exact(&mut self, additional: usize) {
+        self.inner.reserve_exact(additional)
+    }
+
+    /// Tries to reserve capacity for at least `additional` more bytes to be pushed into this `UnixString`.
+    ///
+    /// Works just like [`UnixString::reserve`](UnixString::reserve), but returns [`Error::Allocation`]
+    /// instead of panicking or aborting if the capacity overflows or the allocation fails.
+    /// In that case, `self` is left unchanged.
+    ///
+    /// See also: [`Vec::try_reserve`](std::vec::Vec::try_reserve).
+    ///
+    /// ```rust
+    /// use unixstring::UnixString;
+    ///
+    /// let mut unx = UnixString::new();
+    ///
+    /// assert!(unx.try_reserve(10).is_ok());
+    /// assert!(unx.capacity() >= unx.len_with_nul() + 10);
+    ///
+    /// assert!(unx.try_reserve(usize::MAX).is_err());
+    /// ```
+    pub fn try_reserve(&mut self, additional: usize) -> Result<()> {
+        Ok(self.inner.try_reserve(additional)?)
+    }
+
+    /// Shrinks the capacity of this `UnixString` as much as possible.
+    ///
+    /// The nul terminator is kept, so the capacity will never be lower than [`UnixString::len_with_nul`](UnixString::len_with_nul).
+    ///
+    /// See also: [`Vec::shrink_to_fit`](std::vec::Vec::shrink_to_fit).
+    ///
+    /// ```rust
+    /// use unixstring::UnixString;
+    ///
+    /// # use unixstring::Result;
+    /// # fn main() -> Result<()> {
+    /// let mut unx = UnixString::with_capacity(1024);
+    /// unx.push_bytes(b"/usr/bin")?;
+    ///
+    /// unx.shrink_to_fit();
+    ///
+    /// assert!(unx.capacity() >= unx.len_with_nul());
+    /// assert!(unx.capacity() < 1024);
+    /// # Ok(()) }
+    /// ```
+    pub fn shrink_to_fit(&mut self) {
+        self.inner.shrink_to_fit()
+    }
+
+    /// Shrinks the capacity of this `UnixString` with a lower bound.
+    ///
+    /// Just like in [`UnixString::with_capacity`](UnixString::with_capacity), `min_capacity` refers to content bytes:
+    /// room for the nul terminator is kept on top of it, so the capacity will remain at least `min_capacity + 1`.
+    /// The capacity will also never be lower than [`UnixString::len_with_nul`](UnixString::len_with_nul).
+    ///
+    /// If the current capacity is already lower than that, this does nothing.
+    ///
+    /// See also: [`Vec::shrink_to`](std::vec::Vec::shrink_to).
+    ///
+    /// ```rust
+    /// use unixstring::UnixString;
+    ///
+    /// # use unixstring::Result;
+    /// # fn main() -> Result<()> {
+    /// let mut unx = UnixString::with_capacity(1024);
+    /// unx.push_bytes(b"/usr/bin")?;
+    ///
+    /// unx.shrink_to(64);
+    /// assert!(unx.capacity() >= 65);
+    /// assert!(unx.capacity() < 1024);
+    ///
+    /// unx.shrink_to(0);
+    /// assert!(unx.capacity() >= unx.len_with_nul());
+    /// # Ok(()) }
+    /// ```
+    pub fn shrink_to(&mut self, min_capacity: usize) {
+        self.inner.shrink_to(min_capacity.saturating_add(1))
+    }
+
     /// Returns the length of the underlying byte string *without* considering the nul terminator.
     ///
     /// ```rust
@@ -627,7 +2626,7 @@ impl UnixString {
     ///
     /// assert!(unx.is_empty());
     ///
-    /// unx.push("123321")?;
+    /// unx.push_bytes(b"123321")?;
     ///
     /// assert_eq!(unx.is_empty(), false);
     ///
@@ -637,6 +2636,218 @@ impl UnixString {
     pub fn is_empty(&self) -> bool {
         matches!(&*self.inner, &[0])
     }
+
+    /// Removes the last byte of this `UnixString` (not considering its nul terminator) and returns it.
+    ///
+    /// Returns `None` if this `UnixString` is empty. The capacity of the `UnixString` is not changed.
+    ///
+    /// ```rust
+    /// use unixstring::UnixString;
+    ///
+    /// # use unixstring::Result;
+    /// # fn main() -> Result<()> {
+    /// let mut unx = UnixString::from_string("/home/".into())?;
+    ///
+    /// assert_eq!(unx.pop(), Some(b'/'));
+    /// assert_eq!(unx.to_str()?, "/home");
+    ///
+    /// let mut empty = UnixString::new();
+    /// assert_eq!(empty.pop(), None);
+    /// # Ok(()) }
+    /// ```
+    pub fn pop(&mut self) -> Option<u8> {
+        if self.is_empty() {
+            return None;
+        }
+
+        let removed = self.inner.pop();
+        debug_assert!(removed == Some(0));
+
+        let last = self.inner.last_mut()?;
+        Some(core::mem::replace(last, 0))
+    }
+
+    /// Removes the byte at position `index` from this `UnixString`, shifting all bytes after it to the left, and returns it.
+    ///
+    /// The nul terminator is never removed.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `index >= len`, where `len` is the length of this `UnixString` *without* its nul terminator.
+    ///
+    /// ```rust
+    /// use unixstring::UnixString;
+    ///
+    /// # use unixstring::Result;
+    /// # fn main() -> Result<()> {
+    /// let mut unx = UnixString::from_string("/home//user".into())?;
+    ///
+    /// assert_eq!(unx.remove(5), b'/');
+    /// assert_eq!(unx.to_str()?, "/home/user");
+    /// # Ok(()) }
+    /// ```
+    pub fn remove(&mut self, index: usize) -> u8 {
+        let len = self.len();
+        assert!(
+            index < len,
+            "removal index (is {}) should be < len (is {})",
+            index,
+            len
+        );
+
+        self.inner.remove(index)
+    }
+
+    /// Resizes this `UnixString` so that its length (not considering its nul terminator) is `new_len`.
+    ///
+    /// If `new_len` is greater than the current length, the `UnixString` is extended with `fill` bytes.
+    /// Otherwise, it is truncated to its first `new_len` bytes. The nul terminator is kept at the end either way.
+    ///
+    /// Mirrors [`Vec::resize`](alloc::vec::Vec::resize), but fails with [`Error::InteriorNulByte`] if `fill`
+    /// is zero and the `UnixString` would grow, in which case `self` is left unchanged.
+    ///
+    /// ```rust
+    /// use unixstring::UnixString;
+    ///
+    /// # use unixstring::Result;
+    /// # fn main() -> Result<()> {
+    /// let mut unx = UnixString::from_string("abc".into())?;
+    ///
+    /// unx.resize(6, b'-')?;
+    /// assert_eq!(unx.to_str()?, "abc---");
+    ///
+    /// unx.resize(2, b'-')?;
+    /// assert_eq!(unx.to_str()?, "ab");
+    ///
+    /// assert!(unx.resize(4, 0).is_err());
+    /// # Ok(()) }
+    /// ```
+    pub fn resize(&mut self, new_len: usize, fill: u8) -> Result<()> {
+        let len = self.len();
+        if new_len > len && fill == 0 {
+            return Err(Error::InteriorNulByte { position: len });
+        }
+
+        self.inner.pop();
+        self.inner.resize(new_len, fill);
+        self.inner.push(0);
+
+        Ok(())
+    }
+
+    /// Removes the specified range of bytes from this `UnixString`, returning the removed bytes.
+    ///
+    /// The range refers to the bytes of the `UnixString` *without* its nul terminator, which is never removed.
+    /// Draining the full range (`..`) is therefore equivalent to [`UnixString::clear`](UnixString::clear).
+    ///
+    /// # Panics
+    ///
+    /// Panics if the starting point is greater than the end point or if the end point is greater than
+    /// the length of this `UnixString` (without its nul terminator).
+    ///
+    /// ```rust
+    /// use unixstring::UnixString;
+    ///
+    /// # use unixstring::Result;
+    /// # fn main() -> Result<()> {
+    /// let mut unx = UnixString::from_string("/home/user/file".into())?;
+    ///
+    /// assert_eq!(unx.drain(5..10), b"/user");
+    /// assert_eq!(unx.to_str()?, "/home/file");
+    ///
+    /// assert_eq!(unx.drain(..), b"/home/file");
+    /// assert!(unx.is_empty());
+    /// # Ok(()) }
+    /// ```
+    pub fn drain(&mut self, range: impl RangeBounds<usize>) -> Vec<u8> {
+        let len = self.len();
+        let range = resolve_range(range, len).unwrap_or_else(|| {
+            panic!("drain range out of bounds for UnixString of length {}", len)
+        });
+
+        self.inner.drain(range).collect()
+    }
+
+    /// Truncates this `UnixString`, removing all of its contents but keeping its nul terminator.
+    ///
+    /// This method has no effect on the allocated capacity of the `UnixString`, so it can be
+    /// reused as a buffer without reallocating.
+    ///
+    /// ```rust
+    /// use unixstring::UnixString;
+    ///
+    /// # use unixstring::Result;
+    /// # fn main() -> Result<()> {
+    /// let mut unx = UnixString::with_capacity(16);
+    /// unx.push_bytes(b"/etc/passwd")?;
+    ///
+    /// let capacity = unx.capacity();
+    /// unx.clear();
+    ///
+    /// assert!(unx.is_empty());
+    /// assert_eq!(unx.capacity(), capacity);
+    /// # Ok(()) }
+    /// ```
+    pub fn clear(&mut self) {
+        self.inner.truncate(1);
+        self.inner[0] = 0;
+    }
+
+    /// Takes the contents of this `UnixString`, leaving an empty one in its place.
+    ///
+    /// Works just like [`core::mem::take`], and the returned `UnixString` keeps the allocation of `self`.
+    ///
+    /// ```rust
+    /// use unixstring::UnixString;
+    ///
+    /// # use unixstring::Result;
+    /// # fn main() -> Result<()> {
+    /// let mut unx = UnixString::from_string("/etc/passwd".into())?;
+    ///
+    /// let taken = unx.take();
+    ///
+    /// assert_eq!(taken.to_str()?, "/etc/passwd");
+    /// assert!(unx.is_empty());
+    /// # Ok(()) }
+    /// ```
+    pub fn take(&mut self) -> UnixString {
+        core::mem::take(self)
+    }
+
+    /// Overwrites the contents of this `UnixString` with a copy of `bytes`, reusing its allocation if possible.
+    ///
+    /// This is the amortizing counterpart of creating a new `UnixString` from `bytes`: it only allocates when the
+    /// current capacity is not large enough to hold them.
+    ///
+    /// Just like [`UnixString::push_bytes`](UnixString::push_bytes), this method fails if the bytes contain an interior zero byte,
+    /// in which case `self` is left unchanged.
+    ///
+    /// ```rust
+    /// use unixstring::UnixString;
+    ///
+    /// # use unixstring::Result;
+    /// # fn main() -> Result<()> {
+    /// let mut unx = UnixString::with_capacity(16);
+    /// let capacity = unx.capacity();
+    ///
+    /// for path in ["/etc/passwd", "/etc/group"] {
+    ///     unx.clone_from_slice(path.as_bytes())?;
+    ///     assert_eq!(unx.to_str()?, path);
+    /// }
+    ///
+    /// assert_eq!(unx.capacity(), capacity);
+    /// # Ok(()) }
+    /// ```
+    pub fn clone_from_slice(&mut self, bytes: &[u8]) -> Result<()> {
+        let bytes = strip_nul_terminator(bytes)?;
+
+        self.inner.clear();
+        self.inner.reserve(bytes.len() + 1);
+        self.inner.extend_from_slice(bytes);
+        self.inner.push(0);
+
+        Ok(())
+    }
 }
 
 impl From<CString> for UnixString {
@@ -648,3 +2859,11 @@ impl From<CString> for UnixString {
         }
     }
 }
+
+impl From<&CStr> for UnixString {
+    fn from(value: &CStr) -> Self {
+        Self {
+            inner: value.to_bytes_with_nul().to_vec(),
+        }
+    }
+}
@@ -0,0 +1,62 @@
+use core::fmt;
+#[cfg(feature = "std")]
+use std::io;
+
+#[cfg(feature = "std")]
+use crate::error::Error;
+use crate::{memchr::find_nul_byte, UnixString};
+
+#[cfg(feature = "std")]
+impl io::Write for UnixString {
+    /// Appends the given bytes to this `UnixString`.
+    ///
+    /// Since the written bytes are always followed by the nul terminator, any zero byte found in `buf` is interior to the
+    /// resulting string: in this case an error of kind [`InvalidData`](io::ErrorKind::InvalidData) is returned and `self`
+    /// is left unchanged.
+    fn write(&mut self, buf: &[u8]) -> io::Result<usize> {
+        if let Some(nul_pos) = find_nul_byte(buf) {
+            return Err(io::Error::new(
+                io::ErrorKind::InvalidData,
+                Error::InteriorNulByte { position: nul_pos },
+            ));
+        }
+
+        // The zero bytes were already checked for, so there's no need to go through `push_bytes`
+        self.extend_slice(buf);
+
+        Ok(buf.len())
+    }
+
+    fn flush(&mut self) -> io::Result<()> {
+        Ok(())
+    }
+}
+
+impl fmt::Write for UnixString {
+    /// Appends the given string slice to this `UnixString`.
+    ///
+    /// Just like with [`io::Write`], any zero byte found in `s` would be interior to the resulting string,
+    /// so [`fmt::Error`] is returned and `self` is left unchanged.
+    ///
+    /// Do note that `write!` may call this method several times, so the pieces written before the failing one are kept.
+    ///
+    /// ```rust
+    /// use std::fmt::Write;
+    ///
+    /// use unixstring::UnixString;
+    ///
+    /// let mut unx = UnixString::new();
+    /// write!(unx, "/proc/{}/stat", 1).unwrap();
+    ///
+    /// assert_eq!(unx.to_str().unwrap(), "/proc/1/stat");
+    /// ```
+    fn write_str(&mut self, s: &str) -> fmt::Result {
+        if find_nul_byte(s.as_bytes()).is_some() {
+            return Err(fmt::Error);
+        }
+
+        self.extend_slice(s.as_bytes());
+
+        Ok(())
+    }
+}
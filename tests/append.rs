@@ -0,0 +1,37 @@
+use unixstring::UnixString;
+
+#[test]
+fn append() {
+    let mut unx = UnixString::from_string("/home".into()).unwrap();
+    let other = UnixString::from_string("/user".into()).unwrap();
+
+    unx.append(other);
+
+    assert_eq!(unx.as_bytes_with_nul(), b"/home/user\0");
+    assert_eq!(unx.len(), 10);
+    assert!(unx.validate().is_ok());
+}
+
+#[test]
+fn append_empty() {
+    let mut unx = UnixString::from_string("/home".into()).unwrap();
+
+    unx.append(UnixString::new());
+    assert_eq!(unx.as_bytes_with_nul(), b"/home\0");
+    assert!(unx.validate().is_ok());
+
+    let mut empty = UnixString::new();
+    empty.append(unx);
+    assert_eq!(empty.as_bytes_with_nul(), b"/home\0");
+    assert!(empty.validate().is_ok());
+}
+
+#[test]
+fn append_to_empty_with_capacity() {
+    let mut unx = UnixString::with_capacity(64);
+
+    unx.append(UnixString::from_string("abc".into()).unwrap());
+
+    assert_eq!(unx.as_bytes_with_nul(), b"abc\0");
+    assert!(unx.capacity() >= 64);
+}
@@ -0,0 +1,54 @@
+use unixstring::{Error, UnixString};
+
+#[test]
+fn as_mut_bytes() {
+    let mut unx = UnixString::from_string("/home/user".into()).unwrap();
+
+    assert_eq!(unx.as_mut_bytes().len(), unx.len());
+
+    unx.as_mut_bytes().reverse();
+    assert_eq!(unx.as_bytes_with_nul(), b"resu/emoh/\0");
+
+    unx.as_mut_bytes().make_ascii_uppercase();
+    assert_eq!(unx.as_bytes_with_nul(), b"RESU/EMOH/\0");
+    assert!(unx.validate().is_ok());
+}
+
+#[test]
+fn as_mut_bytes_empty() {
+    let mut unx = UnixString::new();
+
+    assert!(unx.as_mut_bytes().is_empty());
+}
+
+#[test]
+fn as_mut_bytes_writing_zero() {
+    let mut unx = UnixString::from_string("/home/user".into()).unwrap();
+
+    unx.as_mut_bytes()[5] = 0;
+
+    assert_eq!(
+        unx.validate().unwrap_err(),
+        Error::InteriorNulByte { position: 5 }
+    );
+}
+
+#[test]
+fn as_bytes_with_nul_mut() {
+    let mut unx = UnixString::from_string("/home/user".into()).unwrap();
+
+    let bytes = unsafe { unx.as_bytes_with_nul_mut() };
+    assert_eq!(bytes, b"/home/user\0");
+
+    // Mock an FFI call writing its own, earlier nul terminator
+    bytes[..6].copy_from_slice(b"/root\0");
+    assert_eq!(
+        unx.validate().unwrap_err(),
+        Error::InteriorNulByte { position: 5 }
+    );
+
+    let bytes = unsafe { unx.as_bytes_with_nul_mut() };
+    bytes[5..].copy_from_slice(b"/user\0");
+    assert!(unx.validate().is_ok());
+    assert_eq!(unx.as_bytes(), b"/root/user");
+}
@@ -26,7 +26,7 @@ fn as_mut_ptr_invalidating_nul_terminator() {
 
     unsafe { ptr.write_bytes(5, 51) };
 
-    assert!(matches!(unx.validate(), Err(Error::MissingNulTerminator)));
+    assert_eq!(unx.validate().unwrap_err(), Error::MissingNulTerminator);
 }
 
 #[test]
@@ -39,5 +39,25 @@ fn as_mut_ptr_invalidating_interior_nul_byte() {
     // Invalidate the UnixString by adding an interior nul byte
     unsafe { ptr.add(5).write(0) }
 
-    assert!(matches!(unx.validate(), Err(Error::InteriorNulByte)));
+    assert_eq!(
+        unx.validate().unwrap_err(),
+        Error::InteriorNulByte { position: 5 }
+    );
+}
+
+#[test]
+fn try_as_c_str() {
+    let mut unx = UnixString::from_string("hello world".into()).unwrap();
+
+    assert_eq!(unx.try_as_c_str().unwrap(), unx.as_c_str());
+
+    let ptr = unx.as_mut_ptr();
+    unsafe { ptr.add(5).write(0) };
+    assert_eq!(
+        unx.try_as_c_str().unwrap_err(),
+        Error::InteriorNulByte { position: 5 }
+    );
+
+    unsafe { ptr.add(11).write(b'!' as _) };
+    assert_eq!(unx.try_as_c_str().unwrap_err(), Error::MissingNulTerminator);
 }
@@ -1,12 +1,16 @@
+use std::ffi::{CStr, CString};
+
+#[cfg(feature = "std")]
 use std::{
     convert::TryFrom,
-    ffi::{CStr, CString, OsStr},
+    ffi::OsStr,
     path::{Path, PathBuf},
 };
 
 use unixstring::UnixString;
 
 #[test]
+#[cfg(feature = "std")]
 fn as_ref_path() {
     let home = "home/user/";
     let pathbuf = PathBuf::from(&home);
@@ -18,6 +22,7 @@ fn as_ref_path() {
 }
 
 #[test]
+#[cfg(feature = "std")]
 fn as_ref_os_str() {
     let home = "home/user/";
     let pathbuf = PathBuf::from(&home);
@@ -32,7 +37,7 @@ fn as_ref_os_str() {
 fn as_ref_cstr() {
     let home = CString::new("home/user/").unwrap();
 
-    let unix_string = UnixString::try_from(home.clone()).unwrap();
+    let unix_string = UnixString::from(home.clone());
     let unix_string_cstr: &CStr = unix_string.as_ref();
 
     assert_eq!(home.as_c_str(), unix_string_cstr);
@@ -0,0 +1,81 @@
+use unixstring::UnixString;
+
+#[test]
+fn eq_ignore_ascii_case() {
+    let unx = UnixString::from_string("HELLO".into()).unwrap();
+
+    assert!(unx.eq_ignore_ascii_case("hello"));
+    assert!(unx.eq_ignore_ascii_case("HeLlO"));
+    assert!(unx.eq_ignore_ascii_case(b"HELLO"));
+
+    assert!(!unx.eq_ignore_ascii_case("hell"));
+    assert!(!unx.eq_ignore_ascii_case("helloo"));
+    assert!(!unx.eq_ignore_ascii_case("hello\0"));
+}
+
+#[test]
+fn eq_ignore_ascii_case_non_ascii() {
+    let unx = UnixString::from_string("ÉCOLE".into()).unwrap();
+
+    assert!(unx.eq_ignore_ascii_case("École"));
+    assert!(!unx.eq_ignore_ascii_case("école"));
+
+    let unx = UnixString::from_bytes(vec![b'A', 0xFF]).unwrap();
+    assert!(unx.eq_ignore_ascii_case([b'a', 0xFF]));
+    assert!(!unx.eq_ignore_ascii_case([b'a', 0xFE]));
+}
+
+#[test]
+fn eq_ignore_ascii_case_empty() {
+    let unx = UnixString::new();
+
+    assert!(unx.eq_ignore_ascii_case(""));
+    assert!(!unx.eq_ignore_ascii_case("a"));
+}
+
+#[test]
+fn make_ascii_lowercase() {
+    let mut unx = UnixString::from_string("/Home/USER/Ünïcode.TXT".into()).unwrap();
+
+    unx.make_ascii_lowercase();
+
+    assert_eq!(unx.to_str().unwrap(), "/home/user/Ünïcode.txt");
+    assert!(unx.validate().is_ok());
+}
+
+#[test]
+fn make_ascii_uppercase() {
+    let mut unx = UnixString::from_string("/Home/user/ünïcode.txt".into()).unwrap();
+
+    unx.make_ascii_uppercase();
+
+    assert_eq!(unx.to_str().unwrap(), "/HOME/USER/üNïCODE.TXT");
+    assert!(unx.validate().is_ok());
+}
+
+#[test]
+fn make_ascii_case_empty() {
+    let mut unx = UnixString::new();
+
+    unx.make_ascii_lowercase();
+    unx.make_ascii_uppercase();
+
+    assert_eq!(unx.as_bytes_with_nul(), &[0]);
+}
+
+#[test]
+fn to_ascii_case() {
+    let unx = UnixString::from_bytes(vec![b'a', 0xFF, b'B']).unwrap();
+
+    assert_eq!(
+        unx.to_ascii_lowercase().as_bytes_with_nul(),
+        &[b'a', 0xFF, b'b', 0]
+    );
+    assert_eq!(
+        unx.to_ascii_uppercase().as_bytes_with_nul(),
+        &[b'A', 0xFF, b'B', 0]
+    );
+
+    // The original value is not modified
+    assert_eq!(unx.as_bytes_with_nul(), &[b'a', 0xFF, b'B', 0]);
+}
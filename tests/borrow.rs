@@ -0,0 +1,57 @@
+use std::{
+    collections::{hash_map::DefaultHasher, BTreeSet, HashMap, HashSet},
+    hash::{Hash, Hasher},
+};
+
+use unixstring::UnixString;
+
+fn hash_of<T: Hash + ?Sized>(value: &T) -> u64 {
+    let mut hasher = DefaultHasher::new();
+    value.hash(&mut hasher);
+    hasher.finish()
+}
+
+#[test]
+fn hash_matches_as_bytes() {
+    let unx = UnixString::from_string("/home/user".into()).unwrap();
+
+    assert_eq!(hash_of(&unx), hash_of(unx.as_bytes()));
+    assert_eq!(hash_of(&unx), hash_of(&b"/home/user"[..]));
+    assert_eq!(hash_of(&UnixString::new()), hash_of(&b""[..]));
+}
+
+#[test]
+fn hash_map_lookup_with_bytes() {
+    let mut map = HashMap::new();
+    map.insert(UnixString::from_string("/etc/passwd".into()).unwrap(), 1);
+    map.insert(UnixString::from_string("/etc/shadow".into()).unwrap(), 2);
+    map.insert(UnixString::new(), 3);
+
+    assert_eq!(map.get(&b"/etc/passwd"[..]), Some(&1));
+    assert_eq!(map.get(&b"/etc/shadow"[..]), Some(&2));
+    assert_eq!(map.get(&b""[..]), Some(&3));
+    assert_eq!(map.get(&b"/etc/group"[..]), None);
+    // The nul terminator is not part of the key
+    assert_eq!(map.get(&b"/etc/passwd\0"[..]), None);
+}
+
+#[test]
+fn hash_set_of_unix_strings() {
+    let mut set = HashSet::new();
+    set.insert(UnixString::from_string("/usr/lib".into()).unwrap());
+    set.insert(UnixString::from_bytes(b"/usr/lib\0".to_vec()).unwrap());
+
+    assert_eq!(set.len(), 1);
+}
+
+#[test]
+fn btree_set_lookup_with_bytes() {
+    let set: BTreeSet<UnixString> = ["ab", "abc", "b"]
+        .iter()
+        .map(|s| UnixString::from_string(s.to_string()).unwrap())
+        .collect();
+
+    assert!(set.contains(&b"abc"[..]));
+    assert!(set.contains(&b"b"[..]));
+    assert!(!set.contains(&b"a"[..]));
+}
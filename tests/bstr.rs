@@ -0,0 +1,20 @@
+#![cfg(feature = "bstr")]
+
+use bstr::ByteSlice;
+use unixstring::UnixString;
+
+#[test]
+fn as_bstr() {
+    let unx = UnixString::from_bytes(b"/home/\xFFuser".to_vec()).unwrap();
+    let bstr = unx.as_bstr();
+
+    assert_eq!(bstr, &b"/home/\xFFuser"[..]);
+    assert_eq!(bstr.find("user"), Some(7));
+    assert_eq!(format!("{}", bstr), "/home/\u{FFFD}user");
+    assert_eq!(format!("{:?}", bstr), r#""/home/\xffuser""#);
+}
+
+#[test]
+fn as_bstr_empty() {
+    assert!(UnixString::new().as_bstr().is_empty());
+}
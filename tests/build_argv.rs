@@ -0,0 +1,29 @@
+use std::ffi::CStr;
+
+use unixstring::UnixString;
+
+#[test]
+fn build_argv() {
+    let args: Vec<UnixString> = ["/bin/echo", "hello", "world"]
+        .iter()
+        .map(|&arg| UnixString::from_string(arg.into()).unwrap())
+        .collect();
+
+    let argv = UnixString::build_argv(&args);
+
+    assert_eq!(argv.len(), args.len() + 1);
+    assert!(argv.last().unwrap().is_null());
+
+    for (&ptr, arg) in argv.iter().zip(&args) {
+        assert_eq!(ptr, arg.as_ptr());
+        assert_eq!(unsafe { CStr::from_ptr(ptr) }, arg.as_c_str());
+    }
+}
+
+#[test]
+fn build_argv_empty() {
+    let argv = UnixString::build_argv(&[]);
+
+    assert_eq!(argv.len(), 1);
+    assert!(argv[0].is_null());
+}
@@ -0,0 +1,72 @@
+use unixstring::UnixString;
+
+#[test]
+fn bytes() {
+    let unx = UnixString::from_string("/home/user".into()).unwrap();
+
+    assert_eq!(unx.bytes().collect::<Vec<_>>(), b"/home/user");
+    assert_eq!(unx.bytes().count(), unx.len());
+    assert!(unx.bytes().all(|byte| byte != 0));
+}
+
+#[test]
+fn bytes_empty() {
+    let unx = UnixString::new();
+
+    assert_eq!(unx.bytes().next(), None);
+    assert_eq!(unx.byte_indices().next(), None);
+}
+
+#[test]
+fn byte_indices() {
+    let unx = UnixString::from_string("/usr".into()).unwrap();
+
+    let indices: Vec<_> = unx.byte_indices().collect();
+
+    assert_eq!(indices, [(0, b'/'), (1, b'u'), (2, b's'), (3, b'r')]);
+}
+
+#[test]
+fn chunks() {
+    let unx = UnixString::from_string("aabbccd".into()).unwrap();
+
+    let chunks: Vec<&[u8]> = unx.chunks(2).collect();
+    assert_eq!(chunks, [&b"aa"[..], b"bb", b"cc", b"d"]);
+
+    let chunks: Vec<&[u8]> = unx.chunks(7).collect();
+    assert_eq!(chunks, [&b"aabbccd"[..]]);
+
+    let chunks: Vec<&[u8]> = unx.chunks(100).collect();
+    assert_eq!(chunks, [&b"aabbccd"[..]]);
+
+    assert_eq!(UnixString::new().chunks(4).next(), None);
+}
+
+#[test]
+fn windows() {
+    let unx = UnixString::from_string("abcd".into()).unwrap();
+
+    let windows: Vec<&[u8]> = unx.windows(2).collect();
+    assert_eq!(windows, [&b"ab"[..], b"bc", b"cd"]);
+
+    // The nul terminator is never part of a window
+    let windows: Vec<&[u8]> = unx.windows(4).collect();
+    assert_eq!(windows, [&b"abcd"[..]]);
+    assert_eq!(unx.windows(5).next(), None);
+}
+
+#[test]
+#[should_panic]
+fn chunks_of_zero_size() {
+    let unx = UnixString::from_string("abcd".into()).unwrap();
+
+    let _ = unx.chunks(0);
+}
+
+#[test]
+#[should_panic]
+fn windows_of_zero_size() {
+    let unx = UnixString::from_string("abcd".into()).unwrap();
+
+    let _ = unx.windows(0);
+}
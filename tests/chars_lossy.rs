@@ -0,0 +1,49 @@
+use unixstring::UnixString;
+
+#[test]
+fn chars_lossy() {
+    let unx = UnixString::from_string("/home/usér/👶".into()).unwrap();
+
+    assert_eq!(unx.chars_lossy().collect::<String>(), "/home/usér/👶");
+    assert_eq!(UnixString::new().chars_lossy().count(), 0);
+}
+
+#[test]
+fn chars_lossy_matches_from_utf8_lossy() {
+    let inputs: [&[u8]; 7] = [
+        b"abc",
+        b"\xFF",
+        b"a\xFFb",
+        b"\xF0\x9F\x91",
+        b"\xF0\x9F\x91a\xF0\x9F\x91\xB6",
+        b"\xC3\xA9\xED\xA0\x80z",
+        b"\xFE\xFF\xC3",
+    ];
+
+    for bytes in inputs {
+        let unx = UnixString::from_bytes(bytes.to_vec()).unwrap();
+
+        assert_eq!(
+            unx.chars_lossy().collect::<String>(),
+            String::from_utf8_lossy(bytes),
+            "decoding {:?}",
+            bytes
+        );
+    }
+}
+
+#[test]
+fn char_indices_lossy() {
+    let unx = UnixString::from_bytes(b"a\xC3\xA9\xF0\x9F\x91z\xFF".to_vec()).unwrap();
+
+    assert_eq!(
+        unx.char_indices_lossy().collect::<Vec<_>>(),
+        [
+            (0, 'a'),
+            (1, 'é'),
+            (3, char::REPLACEMENT_CHARACTER),
+            (6, 'z'),
+            (7, char::REPLACEMENT_CHARACTER)
+        ]
+    );
+}
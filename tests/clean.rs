@@ -0,0 +1,74 @@
+use unixstring::UnixString;
+
+fn assert_clean(path: &str, expected: &str) {
+    let unx = UnixString::from_string(path.into()).unwrap();
+    let cleaned = unx.clean();
+
+    assert_eq!(cleaned.to_str().unwrap(), expected, "cleaning {:?}", path);
+    assert!(cleaned.validate().is_ok());
+
+    // Cleaning is idempotent
+    assert_eq!(cleaned.clean(), cleaned);
+}
+
+#[test]
+fn clean_already_clean() {
+    assert_clean("abc", "abc");
+    assert_clean("abc/def", "abc/def");
+    assert_clean("a/b/c", "a/b/c");
+    assert_clean(".", ".");
+    assert_clean("..", "..");
+    assert_clean("../..", "../..");
+    assert_clean("../../abc", "../../abc");
+    assert_clean("/abc", "/abc");
+    assert_clean("/", "/");
+}
+
+#[test]
+fn clean_empty() {
+    assert_clean("", ".");
+}
+
+#[test]
+fn clean_separators() {
+    assert_clean("abc/", "abc");
+    assert_clean("abc/def/", "abc/def");
+    assert_clean("./", ".");
+    assert_clean("/abc/", "/abc");
+    assert_clean("abc//def//ghi", "abc/def/ghi");
+    assert_clean("//abc", "/abc");
+    assert_clean("///abc", "/abc");
+    assert_clean("//abc//", "/abc");
+    assert_clean("abc//", "abc");
+}
+
+#[test]
+fn clean_dots() {
+    assert_clean("abc/./def", "abc/def");
+    assert_clean("/./abc/def", "/abc/def");
+    assert_clean("abc/.", "abc");
+    assert_clean("abc/def/ghi/../jkl", "abc/def/jkl");
+    assert_clean("abc/def/../ghi/../jkl", "abc/jkl");
+    assert_clean("abc/def/..", "abc");
+    assert_clean("abc/def/../..", ".");
+    assert_clean("/abc/def/../..", "/");
+    assert_clean("abc/def/../../..", "..");
+    assert_clean("/abc/def/../../..", "/");
+    assert_clean("abc/def/../../../ghi/jkl/../../../mno", "../../mno");
+    assert_clean("/../abc", "/abc");
+    assert_clean("/a/./b/../c", "/a/c");
+}
+
+#[test]
+fn clean_combinations() {
+    assert_clean("abc/./../def", "def");
+    assert_clean("abc//./../def", "def");
+    assert_clean("abc/../../././../def", "../../def");
+}
+
+#[test]
+fn clean_non_utf8() {
+    let unx = UnixString::from_bytes(b"/tmp/\xff/../\xfe/./".to_vec()).unwrap();
+
+    assert_eq!(unx.clean().as_bytes_with_nul(), b"/tmp/\xfe\0");
+}
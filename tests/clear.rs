@@ -0,0 +1,41 @@
+use unixstring::UnixString;
+
+#[test]
+#[cfg(feature = "std")]
+fn clear() {
+    let mut unx = UnixString::with_capacity(32);
+    unx.push("/home/user/file.txt").unwrap();
+
+    let capacity = unx.capacity();
+
+    unx.clear();
+
+    assert!(unx.is_empty());
+    assert_eq!(unx.len(), 0);
+    assert_eq!(unx.as_bytes_with_nul(), &[0]);
+    assert_eq!(unx.capacity(), capacity);
+    assert!(unx.validate().is_ok());
+}
+
+#[test]
+#[cfg(feature = "std")]
+fn clear_then_reuse() {
+    let mut unx = UnixString::new();
+
+    for path in &["/etc/passwd", "/var/log", "/tmp"] {
+        unx.clear();
+        unx.push(path).unwrap();
+
+        assert_eq!(unx.to_str().unwrap(), *path);
+        assert!(unx.validate().is_ok());
+    }
+}
+
+#[test]
+fn clear_empty() {
+    let mut unx = UnixString::new();
+    unx.clear();
+
+    assert!(unx.is_empty());
+    assert!(unx.validate().is_ok());
+}
@@ -0,0 +1,41 @@
+use unixstring::{Error, UnixString};
+
+#[test]
+fn clone_from_slice() {
+    let mut unx = UnixString::from_string("/home/user".into()).unwrap();
+    let capacity = unx.capacity();
+
+    unx.clone_from_slice(b"/tmp").unwrap();
+    assert_eq!(unx.as_bytes_with_nul(), b"/tmp\0");
+    assert!(unx.validate().is_ok());
+
+    unx.clone_from_slice(b"/var/log\0").unwrap();
+    assert_eq!(unx.as_bytes_with_nul(), b"/var/log\0");
+    assert!(unx.validate().is_ok());
+
+    unx.clone_from_slice(b"").unwrap();
+    assert!(unx.is_empty());
+    assert!(unx.validate().is_ok());
+
+    assert_eq!(unx.capacity(), capacity);
+}
+
+#[test]
+fn clone_from_slice_grows() {
+    let mut unx = UnixString::new();
+
+    unx.clone_from_slice(b"/usr/local/share").unwrap();
+    assert_eq!(unx.as_bytes_with_nul(), b"/usr/local/share\0");
+    assert!(unx.validate().is_ok());
+}
+
+#[test]
+fn clone_from_slice_interior_nul() {
+    let mut unx = UnixString::from_string("/home/user".into()).unwrap();
+
+    assert_eq!(
+        unx.clone_from_slice(b"/tmp\0/file").unwrap_err(),
+        Error::InteriorNulByte { position: 4 }
+    );
+    assert_eq!(unx.as_bytes_with_nul(), b"/home/user\0");
+}
@@ -0,0 +1,65 @@
+use unixstring::UnixString;
+
+fn components_of(path: &str) -> Vec<Vec<u8>> {
+    let unx = UnixString::from_string(path.into()).unwrap();
+    unx.components().map(<[u8]>::to_vec).collect()
+}
+
+#[test]
+fn components() {
+    let unx = UnixString::from_string("/home/user/.config".into()).unwrap();
+
+    let components: Vec<&[u8]> = unx.components().collect();
+
+    assert_eq!(components, [&b"home"[..], b"user", b".config"]);
+}
+
+#[test]
+fn components_empty_and_root() {
+    assert!(components_of("").is_empty());
+    assert!(components_of("/").is_empty());
+    assert!(components_of("///").is_empty());
+}
+
+#[test]
+fn components_non_utf8() {
+    let unx = UnixString::from_bytes(b"/tmp/\xff\xfe/file".to_vec()).unwrap();
+
+    let components: Vec<&[u8]> = unx.components().collect();
+
+    assert_eq!(components, [&b"tmp"[..], b"\xff\xfe", b"file"]);
+}
+
+#[test]
+#[cfg(feature = "std")]
+fn components_match_path() {
+    use std::{os::unix::ffi::OsStrExt, path::Path};
+
+    let paths = [
+        "/usr/lib",
+        "/usr/lib/",
+        "//usr///lib//",
+        "usr/lib",
+        "./usr/lib",
+        ".",
+        "./",
+        "./.",
+        ".//a",
+        "a/./b/.",
+        "/./a",
+        "../a/../b",
+        "/..",
+        "..",
+        ".hidden/./file",
+    ];
+
+    for path in paths.iter() {
+        let expected: Vec<Vec<u8>> = Path::new(path)
+            .components()
+            .filter(|component| *component != std::path::Component::RootDir)
+            .map(|component| component.as_os_str().as_bytes().to_vec())
+            .collect();
+
+        assert_eq!(components_of(path), expected, "components of {:?}", path);
+    }
+}
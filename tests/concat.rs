@@ -0,0 +1,45 @@
+use unixstring::{Error, UnixString};
+
+#[test]
+fn concat() {
+    let unx = UnixString::concat(&["/home", "/user", "/.bashrc"]).unwrap();
+    assert_eq!(unx.as_bytes_with_nul(), b"/home/user/.bashrc\0");
+    assert!(unx.validate().is_ok());
+
+    let parts: Vec<Vec<u8>> = vec![b"\xff".to_vec(), b"".to_vec(), b"abc".to_vec()];
+    let unx = UnixString::concat(&parts).unwrap();
+    assert_eq!(unx.as_bytes_with_nul(), b"\xffabc\0");
+    assert_eq!(unx.capacity(), unx.len_with_nul());
+}
+
+#[test]
+fn concat_empty() {
+    let parts: [&str; 0] = [];
+    let unx = UnixString::concat(&parts).unwrap();
+
+    assert!(unx.is_empty());
+    assert!(unx.validate().is_ok());
+
+    let unx = UnixString::concat(&["", ""]).unwrap();
+    assert!(unx.is_empty());
+}
+
+#[test]
+fn concat_with_nul_terminators() {
+    let unx = UnixString::concat(&[&b"abc\0"[..], b"def\0"]).unwrap();
+
+    assert_eq!(unx.as_bytes_with_nul(), b"abcdef\0");
+    assert!(unx.validate().is_ok());
+}
+
+#[test]
+fn concat_interior_nul() {
+    assert_eq!(
+        UnixString::concat(&["/home", "/us\0er"]).unwrap_err(),
+        Error::InteriorNulByte { position: 8 }
+    );
+    assert_eq!(
+        UnixString::concat(&["\0a"]).unwrap_err(),
+        Error::InteriorNulByte { position: 0 }
+    );
+}
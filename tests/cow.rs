@@ -0,0 +1,33 @@
+use std::{
+    borrow::Cow,
+    ffi::{CStr, CString},
+};
+
+use unixstring::UnixString;
+
+fn takes_cow(c_str: Cow<'_, CStr>) -> Vec<u8> {
+    c_str.to_bytes().to_vec()
+}
+
+#[test]
+fn as_cow_c_str() {
+    let unx = UnixString::from_string("/etc/fstab".into()).unwrap();
+
+    let cow = unx.as_cow_c_str();
+    assert!(matches!(cow, Cow::Borrowed(_)));
+    assert_eq!(&*cow, unx.as_c_str());
+
+    assert_eq!(takes_cow(unx.as_cow_c_str()), b"/etc/fstab");
+}
+
+#[test]
+fn into_cow_c_str() {
+    let unx = UnixString::from_string("/etc/fstab".into()).unwrap();
+
+    let cow = unx.clone().into_cow_c_str();
+    assert!(matches!(cow, Cow::Owned(_)));
+    assert_eq!(&*cow, unx.as_c_str());
+    assert_eq!(cow.into_owned(), CString::new("/etc/fstab").unwrap());
+
+    assert_eq!(takes_cow(UnixString::new().into_cow_c_str()), b"");
+}
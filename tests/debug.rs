@@ -0,0 +1,42 @@
+use unixstring::UnixString;
+
+#[test]
+fn debug() {
+    let unx = UnixString::from_string("/home/user".into()).unwrap();
+
+    assert_eq!(format!("{:?}", unx), r#"UnixString("/home/user")"#);
+}
+
+#[test]
+fn debug_empty() {
+    let unx = UnixString::new();
+
+    assert_eq!(format!("{:?}", unx), r#"UnixString("")"#);
+}
+
+#[test]
+fn debug_escapes_non_printable() {
+    let unx = UnixString::from_string("tab\there \"quoted\"\n".into()).unwrap();
+
+    assert_eq!(
+        format!("{:?}", unx),
+        r#"UnixString("tab\there \"quoted\"\n")"#
+    );
+}
+
+#[test]
+fn debug_invalid_utf8() {
+    let unx = UnixString::from_bytes(vec![b'a', 0xFF, b'b']).unwrap();
+
+    assert_eq!(format!("{:?}", unx), "UnixString(\"a\u{FFFD}b\")");
+}
+
+#[test]
+fn debug_interior_nul_byte() {
+    let mut unx = UnixString::from_string("abc".into()).unwrap();
+
+    // Invalidate the UnixString by adding an interior nul byte
+    unsafe { unx.as_mut_ptr().add(1).write(0) }
+
+    assert_eq!(format!("{:?}", unx), "UnixString(\"a\\0c\")");
+}
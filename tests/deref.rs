@@ -0,0 +1,33 @@
+use std::ffi::CStr;
+
+use unixstring::UnixString;
+
+#[test]
+fn deref_to_c_str() {
+    let unx = UnixString::from_string("/home/user".into()).unwrap();
+
+    let c_str: &CStr = &unx;
+    assert_eq!(c_str, unx.as_c_str());
+
+    assert_eq!(unx.to_bytes(), b"/home/user");
+    assert_eq!(unx.to_bytes_with_nul(), b"/home/user\0");
+}
+
+#[test]
+fn deref_coercion() {
+    fn byte_len(c_str: &CStr) -> usize {
+        c_str.to_bytes().len()
+    }
+
+    let unx = UnixString::from_string("abc".into()).unwrap();
+    assert_eq!(byte_len(&unx), 3);
+}
+
+#[test]
+fn inherent_methods_take_precedence() {
+    let unx = UnixString::from_string("abc".into()).unwrap();
+
+    // `UnixString::to_str` returns `unixstring::Result`
+    let result: unixstring::Result<&str> = unx.to_str();
+    assert_eq!(result.unwrap(), "abc");
+}
@@ -0,0 +1,40 @@
+use unixstring::UnixString;
+
+#[test]
+fn display() {
+    let unx = UnixString::from_string("/home/user".into()).unwrap();
+
+    assert_eq!(format!("{}", unx), "/home/user");
+    assert_eq!(unx.to_string(), "/home/user");
+}
+
+#[test]
+fn display_empty() {
+    let unx = UnixString::new();
+
+    assert_eq!(format!("{}", unx), "");
+}
+
+#[test]
+fn display_invalid_utf8() {
+    let unx = UnixString::from_bytes(vec![b'a', 0xFF, b'b']).unwrap();
+
+    assert_eq!(format!("{}", unx), "a\u{FFFD}b");
+}
+
+#[test]
+fn display_respects_padding() {
+    let unx = UnixString::from_string("abc".into()).unwrap();
+
+    assert_eq!(format!("{:>5}", unx), "  abc");
+}
+
+#[test]
+fn display_interior_nul_byte() {
+    let mut unx = UnixString::from_string("abc".into()).unwrap();
+
+    // Invalidate the UnixString by adding an interior nul byte
+    unsafe { unx.as_mut_ptr().add(1).write(0) }
+
+    assert_eq!(format!("{}", unx), "a\0c");
+}
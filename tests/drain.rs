@@ -0,0 +1,66 @@
+use unixstring::UnixString;
+
+#[test]
+fn drain() {
+    let mut unx = UnixString::from_string("/home/user/file".into()).unwrap();
+
+    assert_eq!(unx.drain(5..10), b"/user");
+    assert_eq!(unx.as_bytes_with_nul(), b"/home/file\0");
+
+    assert_eq!(unx.drain(..5), b"/home");
+    assert_eq!(unx.as_bytes_with_nul(), b"/file\0");
+
+    assert_eq!(unx.drain(2..), b"ile");
+    assert_eq!(unx.as_bytes_with_nul(), b"/f\0");
+
+    assert_eq!(unx.drain(0..=0), b"/");
+    assert_eq!(unx.as_bytes_with_nul(), b"f\0");
+
+    assert!(unx.validate().is_ok());
+}
+
+#[test]
+fn drain_full_range() {
+    let mut unx = UnixString::from_string("abc".into()).unwrap();
+    let capacity = unx.capacity();
+
+    assert_eq!(unx.drain(..), b"abc");
+    assert!(unx.is_empty());
+    assert_eq!(unx.as_bytes_with_nul(), &[0]);
+    assert_eq!(unx.capacity(), capacity);
+    assert!(unx.validate().is_ok());
+}
+
+#[test]
+fn drain_empty_range() {
+    let mut unx = UnixString::from_string("abc".into()).unwrap();
+
+    assert!(unx.drain(1..1).is_empty());
+    assert!(unx.drain(3..).is_empty());
+    assert_eq!(unx.as_bytes_with_nul(), b"abc\0");
+}
+
+#[test]
+#[should_panic]
+fn drain_cannot_reach_the_nul_terminator() {
+    let mut unx = UnixString::from_string("abc".into()).unwrap();
+
+    unx.drain(..4);
+}
+
+#[test]
+#[should_panic]
+fn drain_inclusive_cannot_reach_the_nul_terminator() {
+    let mut unx = UnixString::from_string("abc".into()).unwrap();
+
+    unx.drain(0..=3);
+}
+
+#[test]
+#[should_panic]
+#[allow(clippy::reversed_empty_ranges)]
+fn drain_reversed_range() {
+    let mut unx = UnixString::from_string("abc".into()).unwrap();
+
+    unx.drain(2..1);
+}
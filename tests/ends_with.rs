@@ -0,0 +1,90 @@
+#![cfg(feature = "std")]
+
+use std::{ffi::OsStr, os::unix::prelude::OsStrExt};
+
+use unixstring::{Result, UnixString};
+
+#[test]
+pub fn ends_with() -> Result<()> {
+    let mut unix_string = UnixString::new();
+    unix_string.push("/home/")?;
+    unix_string.push("user")?;
+
+    assert!(unix_string.ends_with("user"));
+    assert!(unix_string.ends_with("/home/user"));
+    assert!(!unix_string.ends_with("/home/user/"));
+    assert!(!unix_string.ends_with("other-user"));
+
+    Ok(())
+}
+
+#[test]
+pub fn ends_with_boundaries() -> Result<()> {
+    let mut unix_string = UnixString::new();
+    unix_string.push("lorem ipsum")?;
+
+    assert!(unix_string.ends_with("ipsum"));
+    assert!(unix_string.ends_with("lorem ipsum"));
+
+    assert!(!unix_string.ends_with("lorem ipsun"));
+    assert!(!unix_string.ends_with(" lorem ipsum"));
+    assert!(!unix_string.ends_with("llorem ipsum"));
+
+    Ok(())
+}
+
+#[test]
+pub fn ends_with_empty() -> Result<()> {
+    let mut unix_string = UnixString::new();
+    assert!(unix_string.ends_with(""));
+
+    unix_string.push("/home/")?;
+    assert!(unix_string.ends_with(""));
+
+    Ok(())
+}
+
+#[test]
+pub fn ends_with_non_utf8() -> Result<()> {
+    let unix_string = UnixString::from_bytes(vec![b'a', 0xFF, 0xFE])?;
+
+    assert!(unix_string.ends_with(OsStr::from_bytes(&[0xFF, 0xFE])));
+    assert!(!unix_string.ends_with(OsStr::from_bytes(&[0xFE, 0xFF])));
+
+    Ok(())
+}
+
+#[test]
+pub fn strip_suffix() -> Result<()> {
+    let unix_string = UnixString::from_string("/home/user/data.tar.gz".into())?;
+
+    assert_eq!(
+        unix_string.strip_suffix(".gz"),
+        Some(&b"/home/user/data.tar"[..])
+    );
+    assert_eq!(unix_string.strip_suffix(""), Some(unix_string.as_bytes()));
+    assert_eq!(
+        unix_string.strip_suffix("/home/user/data.tar.gz"),
+        Some(&b""[..])
+    );
+
+    assert_eq!(unix_string.strip_suffix(".xz"), None);
+    assert_eq!(unix_string.strip_suffix("//home/user/data.tar.gz"), None);
+    // The nul terminator is never matched
+    assert_eq!(unix_string.strip_suffix(".gz\0"), None);
+
+    Ok(())
+}
+
+#[test]
+pub fn strip_suffix_non_utf8() -> Result<()> {
+    let unix_string = UnixString::from_bytes(b"/tmp/file.\xff\xfe".to_vec())?;
+
+    assert_eq!(
+        unix_string.strip_suffix(OsStr::from_bytes(b".\xff\xfe")),
+        Some(&b"/tmp/file"[..])
+    );
+    assert_eq!(unix_string.strip_suffix(OsStr::from_bytes(b"\xff")), None);
+
+    Ok(())
+}
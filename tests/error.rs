@@ -0,0 +1,128 @@
+use unixstring::{Error, UnixString};
+
+#[test]
+fn error_eq() {
+    assert_eq!(
+        Error::InteriorNulByte { position: 1 },
+        Error::InteriorNulByte { position: 1 }
+    );
+    assert_ne!(
+        Error::InteriorNulByte { position: 1 },
+        Error::InteriorNulByte { position: 2 }
+    );
+    assert_eq!(Error::MissingNulTerminator, Error::MissingNulTerminator);
+    assert_ne!(
+        Error::InteriorNulByte { position: 0 },
+        Error::MissingNulTerminator
+    );
+
+    assert_eq!(
+        UnixString::from_bytes(b"a\0b".to_vec()).unwrap_err(),
+        Error::InteriorNulByte { position: 1 }
+    );
+}
+
+#[test]
+fn error_eq_utf8() {
+    let invalid = UnixString::from_bytes(vec![0xff, b'a']).unwrap();
+
+    let into_utf8 = invalid.to_str().unwrap_err();
+    assert_eq!(into_utf8, invalid.to_str().unwrap_err());
+
+    let from_utf8 = invalid.clone().into_string().unwrap_err();
+    assert_eq!(from_utf8, invalid.into_string().unwrap_err());
+
+    assert_ne!(into_utf8, from_utf8);
+}
+
+#[test]
+#[cfg(feature = "std")]
+fn error_eq_io() {
+    use std::io;
+
+    let not_found = || Error::Io(io::Error::from_raw_os_error(libc::ENOENT));
+
+    assert_eq!(not_found(), not_found());
+    assert_ne!(
+        not_found(),
+        Error::Io(io::Error::from_raw_os_error(libc::EACCES))
+    );
+    assert_ne!(
+        not_found(),
+        Error::Io(io::Error::new(io::ErrorKind::NotFound, "not found"))
+    );
+}
+
+#[test]
+#[cfg(feature = "std")]
+fn error_source() {
+    use std::{error::Error as _, io};
+
+    let invalid = UnixString::from_bytes(vec![0xff, b'a']).unwrap();
+
+    let into_utf8 = invalid.to_str().unwrap_err();
+    assert!(into_utf8.source().unwrap().is::<std::str::Utf8Error>());
+
+    let from_utf8 = invalid.into_string().unwrap_err();
+    assert!(from_utf8
+        .source()
+        .unwrap()
+        .is::<std::string::FromUtf8Error>());
+
+    let allocation = UnixString::try_with_capacity(usize::MAX).unwrap_err();
+    assert!(allocation
+        .source()
+        .unwrap()
+        .is::<std::collections::TryReserveError>());
+
+    let io = Error::from(io::Error::from_raw_os_error(libc::ENOENT));
+    let source = io.source().unwrap().downcast_ref::<io::Error>().unwrap();
+    assert_eq!(source.raw_os_error(), Some(libc::ENOENT));
+
+    assert!(Error::MissingNulTerminator.source().is_none());
+    assert!(Error::InteriorNulByte { position: 0 }.source().is_none());
+}
+
+#[test]
+fn interior_nul_byte_position() {
+    let mut unx = UnixString::from_string("/home".into()).unwrap();
+
+    assert_eq!(
+        unx.push_bytes(b"/user\0/.bashrc").unwrap_err(),
+        Error::InteriorNulByte { position: 5 }
+    );
+    assert_eq!(
+        unx.try_extend(b"\0\0".iter().copied()).unwrap_err(),
+        Error::InteriorNulByte { position: 0 }
+    );
+    assert_eq!(unx.as_bytes_with_nul(), b"/home\0");
+}
+
+#[test]
+fn interior_nul_byte_display() {
+    let err = UnixString::from_bytes(b"abc\0def".to_vec()).unwrap_err();
+
+    assert_eq!(
+        err.to_string(),
+        "Interior zero byte found at position 3 during CString construction"
+    );
+}
+
+#[test]
+#[cfg(feature = "std")]
+fn error_from_io() {
+    use std::{fs, io};
+
+    fn read_missing_file() -> unixstring::Result<Vec<u8>> {
+        Ok(fs::read("/this/path/does/not/exist")?)
+    }
+
+    assert_eq!(
+        read_missing_file().unwrap_err(),
+        Error::Io(io::Error::from_raw_os_error(libc::ENOENT))
+    );
+    assert_eq!(
+        Error::from(io::Error::from_raw_os_error(libc::EACCES)),
+        Error::Io(io::Error::from_raw_os_error(libc::EACCES))
+    );
+}
@@ -0,0 +1,114 @@
+use unixstring::UnixString;
+
+#[cfg(feature = "std")]
+use unixstring::Error;
+
+#[test]
+fn try_extend() {
+    let mut unx = UnixString::new();
+
+    unx.try_extend(b"/home".iter().copied()).unwrap();
+    assert_eq!(unx.as_bytes_with_nul(), b"/home\0");
+
+    unx.try_extend(b"/user".iter().copied()).unwrap();
+    assert_eq!(unx.as_bytes_with_nul(), b"/home/user\0");
+
+    unx.try_extend(std::iter::empty()).unwrap();
+    assert_eq!(unx.as_bytes_with_nul(), b"/home/user\0");
+
+    assert!(unx.validate().is_ok());
+}
+
+#[test]
+fn try_extend_with_nul_terminator() {
+    let mut unx = UnixString::new();
+
+    unx.try_extend(b"abc\0".iter().copied()).unwrap();
+    assert_eq!(unx.as_bytes_with_nul(), b"abc\0");
+    assert!(unx.validate().is_ok());
+}
+
+#[test]
+#[cfg(feature = "std")]
+fn try_extend_failure_does_not_alter_the_unix_string() {
+    let mut unx = UnixString::new();
+    unx.push("abc").unwrap();
+
+    let err = unx.try_extend(b"de\0f".iter().copied()).unwrap_err();
+    assert_eq!(err, Error::InteriorNulByte { position: 2 });
+
+    assert_eq!(unx.as_bytes_with_nul(), b"abc\0");
+    assert!(unx.validate().is_ok());
+}
+
+#[test]
+fn extend() {
+    let mut unx = UnixString::new();
+    unx.extend(b"hello".iter().copied());
+    unx.extend(vec![b' ', b'w', b'o', b'r', b'l', b'd']);
+
+    assert_eq!(unx.to_str().unwrap(), "hello world");
+    assert!(unx.validate().is_ok());
+}
+
+#[test]
+#[should_panic]
+fn extend_panics_on_interior_nul() {
+    let mut unx = UnixString::new();
+    unx.extend(b"a\0b".iter().copied());
+}
+
+#[test]
+#[cfg(feature = "std")]
+fn try_extend_os() {
+    use std::{ffi::OsStr, path::PathBuf};
+
+    let mut unx = UnixString::new();
+
+    unx.try_extend_os(["/home", "/user"]).unwrap();
+    assert_eq!(unx.as_bytes_with_nul(), b"/home/user\0");
+
+    unx.try_extend_os(vec![PathBuf::from("/.config")]).unwrap();
+    unx.try_extend_os([OsStr::new("/nvim\0")]).unwrap();
+    assert_eq!(unx.as_bytes_with_nul(), b"/home/user/.config/nvim\0");
+
+    unx.try_extend_os(Vec::<&str>::new()).unwrap();
+    assert_eq!(unx.as_bytes_with_nul(), b"/home/user/.config/nvim\0");
+    assert!(unx.validate().is_ok());
+}
+
+#[test]
+#[cfg(feature = "std")]
+fn try_extend_os_keeps_items_before_failure() {
+    let mut unx = UnixString::new();
+
+    let err = unx
+        .try_extend_os(["/home", "/us\0er", "/.config"])
+        .unwrap_err();
+
+    assert_eq!(err, Error::InteriorNulByte { position: 3 });
+    assert_eq!(unx.as_bytes_with_nul(), b"/home\0");
+    assert!(unx.validate().is_ok());
+}
+
+#[test]
+#[cfg(feature = "std")]
+fn extend_os_str_and_path_buf() {
+    use std::{ffi::OsStr, path::PathBuf};
+
+    let mut unx = UnixString::new();
+
+    unx.extend([OsStr::new("/usr"), OsStr::new("/lib")]);
+    unx.extend(vec![PathBuf::from("/libc.so")]);
+
+    assert_eq!(unx.as_bytes_with_nul(), b"/usr/lib/libc.so\0");
+}
+
+#[test]
+#[cfg(feature = "std")]
+#[should_panic]
+fn extend_os_str_panics_on_interior_nul() {
+    let mut unx = UnixString::new();
+
+    unx.extend([std::ffi::OsStr::new("/u\0sr")]);
+}
@@ -1,4 +1,7 @@
-use std::{ffi::CString, path::Path};
+use std::ffi::CString;
+
+#[cfg(feature = "std")]
+use std::path::Path;
 
 use unixstring::UnixString;
 
@@ -18,6 +21,7 @@ fn empty_extend_from_ptr() {
 }
 
 #[test]
+#[cfg(feature = "std")]
 fn extend_from_ptr() {
     let mut unx = UnixString::new();
 
@@ -0,0 +1,42 @@
+use unixstring::{Error, UnixString};
+
+#[test]
+fn extend_from_slices() {
+    let mut unx = UnixString::from_string("/home".into()).unwrap();
+
+    unx.extend_from_slices(&[b"/user", b"/\xFF", b"/file.txt"])
+        .unwrap();
+    assert_eq!(unx.as_bytes_with_nul(), b"/home/user/\xFF/file.txt\0");
+    assert!(unx.validate().is_ok());
+
+    unx.extend_from_slices(&[]).unwrap();
+    unx.extend_from_slices(&[b"", b""]).unwrap();
+    assert_eq!(unx.as_bytes_with_nul(), b"/home/user/\xFF/file.txt\0");
+    assert!(unx.validate().is_ok());
+}
+
+#[test]
+fn extend_from_slices_nul_terminated() {
+    let mut unx = UnixString::new();
+
+    unx.extend_from_slices(&[b"abc\0", b"def", b"ghi\0"])
+        .unwrap();
+    assert_eq!(unx.as_bytes_with_nul(), b"abcdefghi\0");
+    assert!(unx.validate().is_ok());
+}
+
+#[test]
+fn extend_from_slices_interior_nul() {
+    let mut unx = UnixString::from_string("/home".into()).unwrap();
+
+    assert_eq!(
+        unx.extend_from_slices(&[b"/user", b"/fi\0le", b"/other"])
+            .unwrap_err(),
+        Error::InteriorNulByte { position: 8 }
+    );
+    assert_eq!(
+        unx.extend_from_slices(&[b"\0\0"]).unwrap_err(),
+        Error::InteriorNulByte { position: 0 }
+    );
+    assert_eq!(unx.as_bytes_with_nul(), b"/home\0");
+}
@@ -0,0 +1,99 @@
+use unixstring::{Error, UnixString};
+
+fn with_extension(path: &str, extension: &str) -> String {
+    UnixString::from_string(path.into())
+        .unwrap()
+        .with_extension(extension)
+        .unwrap()
+        .into_string()
+        .unwrap()
+}
+
+#[test]
+fn with_extension_replaces() {
+    assert_eq!(with_extension("/tmp/file.tmp", "final"), "/tmp/file.final");
+    assert_eq!(with_extension("file.tmp", "final"), "file.final");
+    assert_eq!(with_extension("archive.tar.gz", "xz"), "archive.tar.xz");
+    assert_eq!(with_extension("file.", "txt"), "file.txt");
+}
+
+#[test]
+fn with_extension_appends() {
+    assert_eq!(with_extension("/tmp/file", "txt"), "/tmp/file.txt");
+    assert_eq!(with_extension(".bashrc", "bak"), ".bashrc.bak");
+    assert_eq!(
+        with_extension("/home/user/.bashrc", "bak"),
+        "/home/user/.bashrc.bak"
+    );
+    assert_eq!(with_extension("/tmp.d/file", "txt"), "/tmp.d/file.txt");
+}
+
+#[test]
+fn with_empty_extension_removes() {
+    assert_eq!(with_extension("/tmp/file.tmp", ""), "/tmp/file");
+    assert_eq!(with_extension("archive.tar.gz", ""), "archive.tar");
+    assert_eq!(with_extension(".bashrc", ""), ".bashrc");
+}
+
+#[test]
+fn with_extension_without_file_name() {
+    assert_eq!(with_extension("/", "txt"), "/");
+    assert_eq!(with_extension("", "txt"), "");
+    assert_eq!(with_extension("/tmp/..", "txt"), "/tmp/..");
+    assert_eq!(with_extension(".", "txt"), ".");
+    assert_eq!(with_extension("/tmp/.", "txt"), "/tmp/.");
+}
+
+#[test]
+fn with_extension_trailing_separator() {
+    assert_eq!(with_extension("/tmp/dir/", "d"), "/tmp/dir.d");
+}
+
+#[test]
+fn set_extension() {
+    let mut unx = UnixString::from_string("/tmp/file.tmp".into()).unwrap();
+
+    assert!(unx.set_extension("final").unwrap());
+    assert_eq!(unx.as_bytes_with_nul(), b"/tmp/file.final\0");
+    assert!(unx.validate().is_ok());
+
+    let mut root = UnixString::from_string("/".into()).unwrap();
+    assert!(!root.set_extension("final").unwrap());
+    assert_eq!(root.as_bytes_with_nul(), b"/\0");
+}
+
+#[test]
+fn set_extension_without_file_name() {
+    for path in [".", "dir/.", "dir/./", "/"] {
+        let mut unx = UnixString::from_string(path.into()).unwrap();
+
+        assert!(!unx.set_extension("xz").unwrap(), "{:?}", path);
+        assert_eq!(unx.as_bytes(), path.as_bytes());
+        assert!(unx.validate().is_ok());
+    }
+}
+
+#[test]
+fn set_extension_rejects_interior_nul() {
+    let mut unx = UnixString::from_string("/tmp/file.tmp".into()).unwrap();
+
+    assert_eq!(
+        unx.set_extension(b"fi\0nal").unwrap_err(),
+        Error::InteriorNulByte { position: 2 }
+    );
+    assert_eq!(unx.as_bytes_with_nul(), b"/tmp/file.tmp\0");
+
+    // A trailing nul terminator is accepted
+    assert!(unx.set_extension(b"final\0").unwrap());
+    assert_eq!(unx.as_bytes_with_nul(), b"/tmp/file.final\0");
+}
+
+#[test]
+fn with_extension_non_utf8() {
+    let unx = UnixString::from_bytes(b"/tmp/\xFF.\xFE".to_vec()).unwrap();
+
+    assert_eq!(
+        unx.with_extension(b"\xFD").unwrap().as_bytes(),
+        b"/tmp/\xFF.\xFD"
+    );
+}
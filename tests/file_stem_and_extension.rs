@@ -0,0 +1,91 @@
+use unixstring::UnixString;
+
+fn stem_and_extension(path: &[u8]) -> (Option<Vec<u8>>, Option<Vec<u8>>) {
+    let unx = UnixString::from_bytes(path.to_vec()).unwrap();
+    let (stem, extension) = unx.file_stem_and_extension();
+
+    (stem.map(<[u8]>::to_vec), extension.map(<[u8]>::to_vec))
+}
+
+fn some(bytes: &[u8]) -> Option<Vec<u8>> {
+    Some(bytes.to_vec())
+}
+
+#[test]
+fn file_stem_and_extension() {
+    assert_eq!(
+        stem_and_extension(b"/tmp/file.txt"),
+        (some(b"file"), some(b"txt"))
+    );
+    assert_eq!(
+        stem_and_extension(b"archive.tar.gz"),
+        (some(b"archive.tar"), some(b"gz"))
+    );
+    assert_eq!(stem_and_extension(b"/tmp/file"), (some(b"file"), None));
+    assert_eq!(stem_and_extension(b"file."), (some(b"file"), some(b"")));
+    assert_eq!(
+        stem_and_extension(b"/tmp/file.txt/"),
+        (some(b"file"), some(b"txt"))
+    );
+    assert_eq!(
+        stem_and_extension(b"/tmp/\xFF.\xFE"),
+        (some(b"\xFF"), some(b"\xFE"))
+    );
+}
+
+#[test]
+fn file_stem_and_extension_dotfiles() {
+    assert_eq!(stem_and_extension(b".bashrc"), (some(b".bashrc"), None));
+    assert_eq!(
+        stem_and_extension(b"/home/user/.bashrc"),
+        (some(b".bashrc"), None)
+    );
+    assert_eq!(
+        stem_and_extension(b".config.toml"),
+        (some(b".config"), some(b"toml"))
+    );
+}
+
+#[test]
+fn file_stem_and_extension_no_file_name() {
+    assert_eq!(stem_and_extension(b"/"), (None, None));
+    assert_eq!(stem_and_extension(b""), (None, None));
+    assert_eq!(stem_and_extension(b"/tmp/.."), (None, None));
+    assert_eq!(stem_and_extension(b"/tmp/."), (None, None));
+}
+
+#[cfg(feature = "std")]
+#[test]
+fn file_stem_and_extension_matches_path() {
+    use std::path::Path;
+
+    for path in [
+        "/tmp/file.txt",
+        "archive.tar.gz",
+        "file.",
+        ".bashrc",
+        ".config.toml",
+        "a..b",
+        "/",
+        "",
+        "/tmp/..",
+    ] {
+        let unx = UnixString::from_string(path.into()).unwrap();
+        let path = Path::new(path);
+
+        let (stem, extension) = unx.file_stem_and_extension();
+        assert_eq!(
+            stem.map(|stem| std::str::from_utf8(stem).unwrap()),
+            path.file_stem().map(|stem| stem.to_str().unwrap()),
+            "stem of {:?}",
+            path
+        );
+        assert_eq!(
+            extension.map(|extension| std::str::from_utf8(extension).unwrap()),
+            path.extension()
+                .map(|extension| extension.to_str().unwrap()),
+            "extension of {:?}",
+            path
+        );
+    }
+}
@@ -0,0 +1,113 @@
+use unixstring::{Result, UnixString};
+
+#[test]
+fn find() -> Result<()> {
+    let unx = UnixString::from_string("/bin:/usr/bin:/usr/local/bin".into())?;
+
+    assert_eq!(unx.find("/usr/"), Some(5));
+    assert_eq!(unx.find("/bin"), Some(0));
+    assert_eq!(unx.find("local"), Some(19));
+    assert_eq!(unx.find("/usr/local/bin"), Some(14));
+    assert_eq!(unx.find("/usr/local/bin/"), None);
+    assert_eq!(unx.find("/sbin"), None);
+
+    Ok(())
+}
+
+#[test]
+fn find_single_byte() -> Result<()> {
+    let unx = UnixString::from_string("/bin:/usr/bin".into())?;
+
+    assert_eq!(unx.find(b":"), Some(4));
+    assert_eq!(unx.find([b'n']), Some(3));
+    assert_eq!(unx.find(b"x"), None);
+
+    Ok(())
+}
+
+#[test]
+fn find_never_matches_nul_terminator() -> Result<()> {
+    let unx = UnixString::from_string("abc".into())?;
+
+    assert_eq!(unx.find([0]), None);
+    assert_eq!(unx.find(b"c\0"), None);
+    assert!(!unx.contains(b"\0"));
+
+    Ok(())
+}
+
+#[test]
+fn find_empty() -> Result<()> {
+    let unx = UnixString::from_string("abc".into())?;
+    assert_eq!(unx.find(""), Some(0));
+    assert!(unx.contains(""));
+
+    let empty = UnixString::new();
+    assert_eq!(empty.find(""), Some(0));
+    assert_eq!(empty.find("a"), None);
+
+    Ok(())
+}
+
+#[test]
+fn contains() -> Result<()> {
+    let unx = UnixString::from_bytes(vec![b'a', 0xFF, b'b'])?;
+
+    assert!(unx.contains([0xFF]));
+    assert!(unx.contains([0xFF, b'b']));
+    assert!(!unx.contains([b'b', 0xFF]));
+
+    Ok(())
+}
+
+#[test]
+fn match_indices() {
+    let unx = UnixString::from_string("/usr/local/lib".into()).unwrap();
+
+    assert_eq!(unx.match_indices(b"/").collect::<Vec<_>>(), [0, 4, 10]);
+    assert_eq!(unx.match_indices(b"l").collect::<Vec<_>>(), [5, 9, 11]);
+    assert_eq!(unx.match_indices(b"/l").collect::<Vec<_>>(), [4, 10]);
+    assert_eq!(
+        unx.match_indices(b"/usr/local/lib").collect::<Vec<_>>(),
+        [0]
+    );
+    assert_eq!(unx.match_indices(b"lib").collect::<Vec<_>>(), [11]);
+    assert_eq!(unx.match_indices(b"/bin").count(), 0);
+}
+
+#[test]
+fn match_indices_non_overlapping() {
+    let unx = UnixString::from_string("aaaaa".into()).unwrap();
+
+    assert_eq!(unx.match_indices(b"aa").collect::<Vec<_>>(), [0, 2]);
+}
+
+#[test]
+fn match_indices_never_matches_nul_terminator() {
+    let unx = UnixString::from_string("abc".into()).unwrap();
+
+    assert_eq!(unx.match_indices(b"\0").count(), 0);
+    assert_eq!(unx.match_indices(b"c\0").count(), 0);
+}
+
+#[test]
+fn match_indices_empty() {
+    let unx = UnixString::from_string("abc".into()).unwrap();
+
+    assert_eq!(unx.match_indices(b"").count(), 0);
+    assert_eq!(UnixString::new().match_indices(b"a").count(), 0);
+}
+
+#[test]
+fn count_byte() {
+    let unx = UnixString::from_bytes(b"/usr/local/\xFF/lib/".to_vec()).unwrap();
+
+    assert_eq!(unx.count_byte(b'/'), 5);
+    assert_eq!(unx.count_byte(b'l'), 3);
+    assert_eq!(unx.count_byte(0xFF), 1);
+    assert_eq!(unx.count_byte(b'x'), 0);
+    // The nul terminator is never counted
+    assert_eq!(unx.count_byte(0), 0);
+
+    assert_eq!(UnixString::new().count_byte(b'/'), 0);
+}
@@ -0,0 +1,41 @@
+use std::fmt::Write;
+
+use unixstring::UnixString;
+
+#[test]
+fn fmt_write() {
+    let mut unx = UnixString::new();
+
+    write!(unx, "/proc/{}/stat", 1234).unwrap();
+
+    assert_eq!(unx.as_bytes_with_nul(), b"/proc/1234/stat\0");
+    assert!(unx.validate().is_ok());
+
+    unx.write_char('/').unwrap();
+    unx.write_str("é").unwrap();
+
+    assert_eq!(unx.to_str().unwrap(), "/proc/1234/stat/é");
+}
+
+#[test]
+fn fmt_write_rejects_zero_bytes() {
+    let mut unx = UnixString::from_string("abc".into()).unwrap();
+
+    assert!(unx.write_str("d\0e").is_err());
+    assert!(unx.write_str("\0").is_err());
+    assert!(unx.write_char('\0').is_err());
+
+    assert_eq!(unx.as_bytes_with_nul(), b"abc\0");
+    assert!(unx.validate().is_ok());
+}
+
+#[test]
+fn fmt_write_keeps_previous_pieces() {
+    let mut unx = UnixString::new();
+
+    let nul = '\0';
+    assert!(write!(unx, "abc{}", nul).is_err());
+
+    assert_eq!(unx.as_bytes_with_nul(), b"abc\0");
+    assert!(unx.validate().is_ok());
+}
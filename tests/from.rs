@@ -1,3 +1,5 @@
+#![cfg(feature = "std")]
+
 use std::{
     ffi::{CString, OsString},
     path::PathBuf,
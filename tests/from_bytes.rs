@@ -17,7 +17,7 @@ fn size_one_vec() {
 
     assert_eq!(one.to_str().unwrap(), "1");
 
-    assert_eq!(one.as_bytes(), &[b'1']);
+    assert_eq!(one.as_bytes(), b"1");
 
     assert_eq!(one.as_bytes_with_nul(), &[b'1', 0]);
 }
@@ -61,3 +61,64 @@ fn from_bytes() {
     // Invalid: an interior nul byte was found
     UnixString::from_bytes(bytes_with_interior_nul).unwrap_err();
 }
+
+#[test]
+fn from_bytes_lossy() {
+    let unx = UnixString::from_bytes_lossy(b"/home/user".to_vec());
+    assert_eq!(unx.as_bytes_with_nul(), b"/home/user\0");
+
+    let unx = UnixString::from_bytes_lossy(b"/home/user\0".to_vec());
+    assert_eq!(unx.as_bytes_with_nul(), b"/home/user\0");
+
+    let unx = UnixString::from_bytes_lossy(b"/home\0/user\0".to_vec());
+    assert_eq!(unx.as_bytes_with_nul(), b"/home\0");
+    assert!(unx.validate().is_ok());
+
+    let unx = UnixString::from_bytes_lossy(b"\0abc".to_vec());
+    assert!(unx.is_empty());
+
+    let unx = UnixString::from_bytes_lossy(Vec::new());
+    assert!(unx.is_empty());
+    assert!(unx.validate().is_ok());
+}
+
+#[test]
+fn from_bytes_with_capacity() {
+    let unx = UnixString::from_bytes_with_capacity(b"/home".to_vec(), 64).unwrap();
+    assert_eq!(unx.as_bytes_with_nul(), b"/home\0");
+    assert!(unx.capacity() >= unx.len_with_nul() + 64);
+
+    let unx = UnixString::from_bytes_with_capacity(b"/home\0".to_vec(), 64).unwrap();
+    assert_eq!(unx.as_bytes_with_nul(), b"/home\0");
+    assert!(unx.capacity() >= unx.len_with_nul() + 64);
+    assert!(unx.validate().is_ok());
+
+    let unx = UnixString::from_bytes_with_capacity(Vec::new(), 0).unwrap();
+    assert!(unx.is_empty());
+    assert!(unx.validate().is_ok());
+}
+
+#[test]
+fn from_bytes_with_capacity_does_not_reallocate() {
+    let mut unx = UnixString::from_bytes_with_capacity(b"/home".to_vec(), 5).unwrap();
+    let capacity = unx.capacity();
+
+    unx.push_bytes(b"/user").unwrap();
+
+    assert_eq!(unx.capacity(), capacity);
+    assert_eq!(unx.as_bytes_with_nul(), b"/home/user\0");
+}
+
+#[test]
+#[should_panic(expected = "capacity overflow")]
+fn from_bytes_with_capacity_too_large() {
+    let _ = UnixString::from_bytes_with_capacity(b"/home".to_vec(), usize::MAX);
+}
+
+#[test]
+fn from_bytes_with_capacity_interior_nul() {
+    assert_eq!(
+        UnixString::from_bytes_with_capacity(b"/ho\0me".to_vec(), 64).unwrap_err(),
+        unixstring::Error::InteriorNulByte { position: 3 }
+    );
+}
@@ -0,0 +1,31 @@
+use std::{convert::TryFrom, ffi::CStr};
+
+use unixstring::UnixString;
+
+#[test]
+fn from_c_str() {
+    let c_str = CStr::from_bytes_with_nul(b"abc\0").unwrap();
+    let unx = UnixString::from(c_str);
+
+    assert_eq!(unx.as_c_str(), c_str);
+    assert_eq!(unx.as_bytes_with_nul(), b"abc\0");
+    assert!(unx.validate().is_ok());
+}
+
+#[test]
+fn from_empty_c_str() {
+    let c_str = CStr::from_bytes_with_nul(b"\0").unwrap();
+    let unx: UnixString = c_str.into();
+
+    assert!(unx.is_empty());
+    assert_eq!(unx.as_c_str(), c_str);
+}
+
+#[test]
+#[allow(clippy::unnecessary_fallible_conversions)]
+fn try_from_c_str() {
+    let c_str = CStr::from_bytes_with_nul(b"/home/user\0").unwrap();
+    let unx = UnixString::try_from(c_str).unwrap();
+
+    assert_eq!(unx.as_c_str(), c_str);
+}
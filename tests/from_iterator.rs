@@ -0,0 +1,64 @@
+use unixstring::UnixString;
+
+#[test]
+fn collect_bytes() {
+    let unx: UnixString = b"/home/user".iter().copied().collect();
+
+    assert_eq!(unx.as_bytes_with_nul(), b"/home/user\0");
+    assert!(unx.validate().is_ok());
+}
+
+#[test]
+fn collect_byte_refs() {
+    let unx: UnixString = b"/home/user".iter().collect();
+
+    assert_eq!(unx.as_bytes_with_nul(), b"/home/user\0");
+    assert!(unx.validate().is_ok());
+}
+
+#[test]
+fn collect_with_nul_terminator() {
+    let unx: UnixString = b"abc\0".iter().collect();
+
+    assert_eq!(unx.as_bytes_with_nul(), b"abc\0");
+}
+
+#[test]
+fn collect_empty() {
+    let unx: UnixString = std::iter::empty::<u8>().collect();
+
+    assert!(unx.is_empty());
+    assert!(unx.validate().is_ok());
+}
+
+#[test]
+#[should_panic]
+fn collect_panics_on_interior_nul() {
+    let _: UnixString = b"a\0bc".iter().collect();
+}
+
+#[test]
+fn try_from_bytes_iter() {
+    let unx = UnixString::try_from_bytes_iter(b"/home/user".iter().copied()).unwrap();
+    assert_eq!(unx.as_bytes_with_nul(), b"/home/user\0");
+    assert!(unx.validate().is_ok());
+
+    let unx = UnixString::try_from_bytes_iter(b"/home/user\0".iter().copied()).unwrap();
+    assert_eq!(unx.as_bytes_with_nul(), b"/home/user\0");
+
+    let unx = UnixString::try_from_bytes_iter(std::iter::empty()).unwrap();
+    assert!(unx.is_empty());
+    assert!(unx.validate().is_ok());
+}
+
+#[test]
+fn try_from_bytes_iter_interior_nul() {
+    assert_eq!(
+        UnixString::try_from_bytes_iter(b"/home\0/user".iter().copied()).unwrap_err(),
+        unixstring::Error::InteriorNulByte { position: 5 }
+    );
+    assert_eq!(
+        UnixString::try_from_bytes_iter(b"\0\0".iter().copied()).unwrap_err(),
+        unixstring::Error::InteriorNulByte { position: 0 }
+    );
+}
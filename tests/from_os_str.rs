@@ -0,0 +1,43 @@
+#![cfg(feature = "std")]
+
+use std::{ffi::OsStr, os::unix::ffi::OsStrExt, path::Path};
+
+use unixstring::{Error, UnixString};
+
+#[test]
+fn from_os_str() {
+    let os_str = OsStr::from_bytes(b"/home/\xFFuser");
+    let unx = UnixString::from_os_str(os_str).unwrap();
+
+    assert_eq!(unx.as_os_str(), os_str);
+    assert_eq!(unx.as_bytes_with_nul(), b"/home/\xFFuser\0");
+    assert!(unx.validate().is_ok());
+
+    let unx = UnixString::from_os_str(OsStr::from_bytes(b"/home\0")).unwrap();
+    assert_eq!(unx.as_bytes_with_nul(), b"/home\0");
+
+    assert!(UnixString::from_os_str(OsStr::new("")).unwrap().is_empty());
+}
+
+#[test]
+fn from_os_str_interior_nul() {
+    assert_eq!(
+        UnixString::from_os_str(OsStr::from_bytes(b"/ho\0me")).unwrap_err(),
+        Error::InteriorNulByte { position: 3 }
+    );
+}
+
+#[test]
+fn from_path() {
+    let path = Path::new("/home/user/file.txt");
+    let unx = UnixString::from_path(path).unwrap();
+
+    assert_eq!(unx.as_path(), path);
+    assert_eq!(unx.as_bytes_with_nul(), b"/home/user/file.txt\0");
+    assert!(unx.validate().is_ok());
+
+    assert_eq!(
+        UnixString::from_path(Path::new("/home\0/user")).unwrap_err(),
+        Error::InteriorNulByte { position: 5 }
+    );
+}
@@ -0,0 +1,65 @@
+use unixstring::{Error, UnixString};
+
+#[test]
+fn from_vec_with_nul() {
+    let unx = UnixString::from_vec_with_nul(b"abc\0".to_vec()).unwrap();
+    assert_eq!(unx.as_bytes_with_nul(), b"abc\0");
+    assert!(unx.validate().is_ok());
+
+    let empty = UnixString::from_vec_with_nul(vec![0]).unwrap();
+    assert!(empty.is_empty());
+}
+
+#[test]
+fn from_vec_with_nul_fails_without_terminator() {
+    assert_eq!(
+        UnixString::from_vec_with_nul(b"abc".to_vec()).unwrap_err(),
+        Error::MissingNulTerminator
+    );
+
+    assert_eq!(
+        UnixString::from_vec_with_nul(vec![]).unwrap_err(),
+        Error::MissingNulTerminator
+    );
+
+    assert_eq!(
+        UnixString::from_vec_with_nul(b"a\0bc".to_vec()).unwrap_err(),
+        Error::MissingNulTerminator
+    );
+}
+
+#[test]
+fn from_vec_with_nul_fails_with_interior_nul() {
+    assert_eq!(
+        UnixString::from_vec_with_nul(b"a\0bc\0".to_vec()).unwrap_err(),
+        Error::InteriorNulByte { position: 1 }
+    );
+
+    assert_eq!(
+        UnixString::from_vec_with_nul(b"\0\0".to_vec()).unwrap_err(),
+        Error::InteriorNulByte { position: 0 }
+    );
+}
+
+#[test]
+fn from_vec_with_nul_unchecked() {
+    let unx = unsafe { UnixString::from_vec_with_nul_unchecked(b"abc\0".to_vec()) };
+
+    assert_eq!(unx.as_bytes_with_nul(), b"abc\0");
+    assert!(unx.validate().is_ok());
+}
+
+#[test]
+fn from_c_string_bytes() {
+    let unx = UnixString::from_c_string_bytes(b"/etc/hosts\0".to_vec()).unwrap();
+    assert_eq!(unx.as_bytes_with_nul(), b"/etc/hosts\0");
+
+    assert_eq!(
+        UnixString::from_c_string_bytes(b"/etc/hosts".to_vec()).unwrap_err(),
+        Error::MissingNulTerminator
+    );
+    assert_eq!(
+        UnixString::from_c_string_bytes(b"/etc\0/hosts\0".to_vec()).unwrap_err(),
+        Error::InteriorNulByte { position: 4 }
+    );
+}
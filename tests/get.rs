@@ -0,0 +1,53 @@
+use std::ops::Bound;
+
+use unixstring::UnixString;
+
+#[test]
+fn get() {
+    let unx = UnixString::from_string("/home/user".into()).unwrap();
+
+    assert_eq!(unx.get(1..5), Some(&b"home"[..]));
+    assert_eq!(unx.get(..5), Some(&b"/home"[..]));
+    assert_eq!(unx.get(6..), Some(&b"user"[..]));
+    assert_eq!(unx.get(1..=4), Some(&b"home"[..]));
+    assert_eq!(unx.get(..), Some(&b"/home/user"[..]));
+    assert_eq!(
+        unx.get((Bound::Excluded(0), Bound::Excluded(5))),
+        Some(&b"home"[..])
+    );
+}
+
+#[test]
+fn get_up_to_len() {
+    let unx = UnixString::from_string("/home/user".into()).unwrap();
+    let len = unx.len();
+
+    assert_eq!(unx.get(..len), Some(&b"/home/user"[..]));
+    assert_eq!(unx.get(len..), Some(&b""[..]));
+    assert_eq!(unx.get(len..len), Some(&b""[..]));
+}
+
+#[test]
+fn get_out_of_bounds() {
+    let unx = UnixString::from_string("/home/user".into()).unwrap();
+
+    assert_eq!(unx.get(..unx.len_with_nul()), None);
+    assert_eq!(unx.get(..=unx.len()), None);
+    assert_eq!(unx.get(unx.len_with_nul()..), None);
+    #[allow(clippy::reversed_empty_ranges)]
+    let reversed = unx.get(5..1);
+    assert_eq!(reversed, None);
+    assert_eq!(unx.get(..=usize::MAX), None);
+    assert_eq!(
+        unx.get((Bound::Excluded(usize::MAX), Bound::Unbounded)),
+        None
+    );
+}
+
+#[test]
+fn get_empty() {
+    let unx = UnixString::new();
+
+    assert_eq!(unx.get(..), Some(&b""[..]));
+    assert_eq!(unx.get(0..1), None);
+}
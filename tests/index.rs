@@ -0,0 +1,43 @@
+use unixstring::UnixString;
+
+#[test]
+fn index_ranges() {
+    let unx = UnixString::from_string("/home/user".into()).unwrap();
+
+    assert_eq!(&unx[1..5], b"home");
+    assert_eq!(&unx[..5], b"/home");
+    assert_eq!(&unx[6..], b"user");
+    assert_eq!(&unx[..], b"/home/user");
+    assert_eq!(&unx[1..=4], b"home");
+}
+
+#[test]
+fn index_up_to_len() {
+    let unx = UnixString::from_string("/home/user".into()).unwrap();
+    let len = unx.len();
+
+    assert_eq!(&unx[..len], b"/home/user");
+    assert_eq!(&unx[len..], b"");
+    assert_eq!(&unx[0..len], b"/home/user");
+}
+
+#[test]
+#[should_panic]
+fn index_range_reaching_nul_terminator() {
+    let unx = UnixString::from_string("/home/user".into()).unwrap();
+    let _ = &unx[..unx.len_with_nul()];
+}
+
+#[test]
+#[should_panic]
+fn index_range_inclusive_reaching_nul_terminator() {
+    let unx = UnixString::from_string("/home/user".into()).unwrap();
+    let _ = &unx[0..=unx.len()];
+}
+
+#[test]
+#[should_panic]
+fn index_range_from_out_of_bounds() {
+    let unx = UnixString::from_string("/home/user".into()).unwrap();
+    let _ = &unx[unx.len_with_nul()..];
+}
@@ -0,0 +1,55 @@
+use unixstring::UnixString;
+
+#[test]
+fn insert_bytes() {
+    let mut unx = UnixString::from_string("/home/file".into()).unwrap();
+
+    unx.insert_bytes(6, b"user/").unwrap();
+    assert_eq!(unx.as_bytes_with_nul(), b"/home/user/file\0");
+
+    unx.insert_bytes(0, b"/mnt").unwrap();
+    assert_eq!(unx.as_bytes_with_nul(), b"/mnt/home/user/file\0");
+
+    unx.insert_bytes(unx.len(), b".txt").unwrap();
+    assert_eq!(unx.as_bytes_with_nul(), b"/mnt/home/user/file.txt\0");
+
+    assert!(unx.validate().is_ok());
+}
+
+#[test]
+fn insert_null_terminated_bytes() {
+    let mut unx = UnixString::from_string("ac".into()).unwrap();
+
+    unx.insert_bytes(1, b"b\0").unwrap();
+    assert_eq!(unx.as_bytes_with_nul(), b"abc\0");
+
+    unx.insert_bytes(0, b"\0").unwrap();
+    assert_eq!(unx.as_bytes_with_nul(), b"abc\0");
+
+    assert!(unx.validate().is_ok());
+}
+
+#[test]
+fn insert_bytes_into_empty() {
+    let mut unx = UnixString::new();
+
+    unx.insert_bytes(0, b"abc").unwrap();
+    assert_eq!(unx.as_bytes_with_nul(), b"abc\0");
+}
+
+#[test]
+fn insert_bytes_failure_does_not_alter_the_unix_string() {
+    let mut unx = UnixString::from_string("abc".into()).unwrap();
+
+    unx.insert_bytes(1, b"d\0e").unwrap_err();
+    assert_eq!(unx.as_bytes_with_nul(), b"abc\0");
+}
+
+#[test]
+#[should_panic]
+fn insert_bytes_out_of_bounds() {
+    let mut unx = UnixString::from_string("abc".into()).unwrap();
+
+    // Inserting after the nul terminator is not allowed
+    let _ = unx.insert_bytes(4, b"d");
+}
@@ -1,6 +1,10 @@
-use std::convert::TryFrom;
 use std::ffi::CString;
+
+#[cfg(feature = "std")]
+use std::convert::TryFrom;
+#[cfg(feature = "std")]
 use std::ffi::OsString;
+#[cfg(feature = "std")]
 use std::path::PathBuf;
 
 use unixstring::UnixString;
@@ -18,6 +22,20 @@ fn into_cstring() {
 }
 
 #[test]
+fn to_c_string() {
+    let unx = UnixString::from_bytes(b"/home/\xFFuser".to_vec()).unwrap();
+
+    let cstring = unx.to_c_string();
+
+    assert_eq!(cstring.as_c_str(), unx.as_c_str());
+    assert_eq!(cstring.as_bytes_with_nul(), unx.as_bytes_with_nul());
+    assert_eq!(cstring, unx.into_cstring());
+
+    assert_eq!(UnixString::new().to_c_string(), CString::default());
+}
+
+#[test]
+#[cfg(feature = "std")]
 fn into_pathbuf() {
     let home = "/home/user";
 
@@ -30,6 +48,20 @@ fn into_pathbuf() {
 }
 
 #[test]
+#[cfg(feature = "std")]
+fn to_path_buf() {
+    let unx = UnixString::from_bytes(b"/home/\xFFuser".to_vec()).unwrap();
+
+    let pathbuf = unx.to_path_buf();
+
+    assert_eq!(pathbuf.as_path(), unx.as_path());
+    assert_eq!(pathbuf, unx.into_pathbuf());
+
+    assert_eq!(UnixString::new().to_path_buf(), PathBuf::new());
+}
+
+#[test]
+#[cfg(feature = "std")]
 fn into_os_string() {
     let home = "/home/user";
 
@@ -40,3 +72,31 @@ fn into_os_string() {
 
     assert_eq!(os_string, unx.into_os_string());
 }
+
+#[test]
+#[cfg(feature = "std")]
+fn to_os_string() {
+    let unx = UnixString::from_bytes(b"/home/\xFFuser".to_vec()).unwrap();
+
+    let os_string = unx.to_os_string();
+
+    assert_eq!(os_string.as_os_str(), unx.as_os_str());
+    assert_eq!(os_string, unx.into_os_string());
+
+    assert_eq!(UnixString::new().to_os_string(), OsString::new());
+}
+
+#[test]
+fn into_inner_preserves_capacity() {
+    let mut unx = UnixString::with_capacity(128);
+    unx.push_bytes(b"/home/user").unwrap();
+
+    let capacity = unx.capacity();
+    let ptr = unx.as_ptr();
+
+    let buf = unx.into_inner();
+
+    assert_eq!(buf, b"/home/user\0");
+    assert_eq!(buf.capacity(), capacity);
+    assert_eq!(buf.as_ptr(), ptr.cast::<u8>());
+}
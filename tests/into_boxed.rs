@@ -0,0 +1,57 @@
+use unixstring::UnixString;
+
+#[test]
+#[cfg(feature = "std")]
+fn into_boxed_c_str() {
+    let mut unx = UnixString::with_capacity(128);
+    unx.push("/home/user").unwrap();
+
+    let boxed = unx.clone().into_boxed_c_str();
+
+    assert_eq!(&*boxed, unx.as_c_str());
+    assert_eq!(boxed.to_bytes_with_nul(), b"/home/user\0");
+}
+
+#[test]
+fn into_boxed_c_str_empty() {
+    let unx = UnixString::new();
+    let boxed = unx.clone().into_boxed_c_str();
+
+    assert_eq!(&*boxed, unx.as_c_str());
+    assert_eq!(boxed.to_bytes_with_nul(), &[0]);
+}
+
+#[test]
+#[cfg(feature = "std")]
+fn into_boxed_bytes() {
+    let mut unx = UnixString::with_capacity(128);
+    unx.push("/home/user").unwrap();
+
+    let boxed = unx.into_boxed_bytes();
+
+    assert_eq!(&*boxed, b"/home/user\0");
+    assert_eq!(boxed.len(), 11);
+}
+
+#[test]
+#[cfg(feature = "std")]
+fn into_arc_c_str() {
+    let mut unx = UnixString::with_capacity(128);
+    unx.push("/home/user").unwrap();
+
+    let shared = unx.clone().into_arc_c_str();
+    let other = std::sync::Arc::clone(&shared);
+
+    assert_eq!(&*shared, unx.as_c_str());
+    assert_eq!(other.to_bytes_with_nul(), b"/home/user\0");
+}
+
+#[test]
+fn into_rc_c_str() {
+    let unx = UnixString::from_string("/usr/lib".into()).unwrap();
+
+    let shared = unx.clone().into_rc_c_str();
+
+    assert_eq!(&*shared, unx.as_c_str());
+    assert_eq!(shared.to_bytes_with_nul(), b"/usr/lib\0");
+}
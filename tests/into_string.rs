@@ -29,3 +29,54 @@ fn into_string() {
 
     assert_eq!(&string, "/usr/bin")
 }
+
+#[test]
+fn into_string_lossy_counted() {
+    let unix_string = UnixString::from_bytes(b"/usr/bin".to_vec()).unwrap();
+    assert_eq!(
+        unix_string.into_string_lossy_counted(),
+        ("/usr/bin".to_string(), 0)
+    );
+
+    let unix_string = UnixString::from_bytes(b"a\xFFb\xF0\x9F\x91c\xFE\xFF".to_vec()).unwrap();
+    assert_eq!(
+        unix_string.into_string_lossy_counted(),
+        ("a\u{FFFD}b\u{FFFD}c\u{FFFD}\u{FFFD}".to_string(), 4)
+    );
+
+    assert_eq!(
+        UnixString::new().into_string_lossy_counted(),
+        (String::new(), 0)
+    );
+}
+
+#[test]
+fn into_string_lossy_counted_existing_replacement_chars() {
+    let unix_string = UnixString::from_string("\u{FFFD}".into()).unwrap();
+    assert_eq!(
+        unix_string.into_string_lossy_counted(),
+        ("\u{FFFD}".to_string(), 0)
+    );
+}
+
+#[test]
+fn into_string_lossy_counted_matches_into_string_lossy() {
+    let inputs: [&[u8]; 5] = [
+        b"abc",
+        b"\xFF",
+        b"\xF0\x9F\x91",
+        b"\xC3\xA9\xED\xA0\x80z",
+        b"\xFE\xFF\xC3",
+    ];
+
+    for bytes in inputs {
+        let lossy = UnixString::from_bytes(bytes.to_vec())
+            .unwrap()
+            .into_string_lossy();
+        let (counted, _) = UnixString::from_bytes(bytes.to_vec())
+            .unwrap()
+            .into_string_lossy_counted();
+
+        assert_eq!(lossy, counted);
+    }
+}
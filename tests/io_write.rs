@@ -0,0 +1,52 @@
+#![cfg(feature = "std")]
+
+use std::io::{self, Write};
+
+use unixstring::UnixString;
+
+#[test]
+fn io_write() {
+    let mut unx = UnixString::new();
+
+    write!(unx, "/proc/{}/stat", 42).unwrap();
+    unx.flush().unwrap();
+
+    assert_eq!(unx.as_bytes_with_nul(), b"/proc/42/stat\0");
+    assert!(unx.validate().is_ok());
+}
+
+#[test]
+fn io_write_all() {
+    let mut unx = UnixString::new();
+
+    unx.write_all(b"/home").unwrap();
+    unx.write_all(b"/user").unwrap();
+    assert_eq!(unx.write(b"").unwrap(), 0);
+
+    assert_eq!(unx.as_bytes_with_nul(), b"/home/user\0");
+}
+
+#[test]
+fn io_copy() {
+    let mut unx = UnixString::new();
+    let mut reader: &[u8] = b"lorem ipsum dolor sit amet";
+
+    let copied = io::copy(&mut reader, &mut unx).unwrap();
+
+    assert_eq!(copied, 26);
+    assert_eq!(unx.to_str().unwrap(), "lorem ipsum dolor sit amet");
+}
+
+#[test]
+fn io_write_rejects_zero_bytes() {
+    let mut unx = UnixString::from_string("abc".into()).unwrap();
+
+    let err = unx.write(b"d\0ef").unwrap_err();
+    assert_eq!(err.kind(), io::ErrorKind::InvalidData);
+
+    let err = unx.write(b"def\0").unwrap_err();
+    assert_eq!(err.kind(), io::ErrorKind::InvalidData);
+
+    assert_eq!(unx.as_bytes_with_nul(), b"abc\0");
+    assert!(unx.validate().is_ok());
+}
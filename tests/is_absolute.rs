@@ -0,0 +1,42 @@
+use unixstring::UnixString;
+
+#[test]
+fn is_absolute() {
+    let unx = UnixString::from_string("/etc/passwd".into()).unwrap();
+    assert!(unx.is_absolute());
+    assert!(!unx.is_relative());
+
+    let root = UnixString::from_string("/".into()).unwrap();
+    assert!(root.is_absolute());
+
+    let unx = UnixString::from_bytes(b"/\xff\xfe".to_vec()).unwrap();
+    assert!(unx.is_absolute());
+}
+
+#[test]
+fn is_relative() {
+    for path in ["etc/passwd", ".", "..", "./a", " /a"].iter() {
+        let unx = UnixString::from_string(path.to_string()).unwrap();
+
+        assert!(unx.is_relative(), "{} should be relative", path);
+        assert!(!unx.is_absolute());
+    }
+}
+
+#[test]
+fn empty_is_relative() {
+    let unx = UnixString::new();
+
+    assert!(unx.is_relative());
+    assert!(!unx.is_absolute());
+}
+
+#[test]
+#[cfg(feature = "std")]
+fn is_absolute_matches_path() {
+    for path in ["/", "/a", "a", "", "./a", "//a"].iter() {
+        let unx = UnixString::from_string(path.to_string()).unwrap();
+
+        assert_eq!(unx.is_absolute(), std::path::Path::new(path).is_absolute());
+    }
+}
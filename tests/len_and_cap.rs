@@ -1,6 +1,7 @@
 use unixstring::UnixString;
 
 #[test]
+#[cfg(feature = "std")]
 fn is_empty() {
     let mut unx = UnixString::new();
 
@@ -15,7 +16,7 @@ fn is_empty() {
 
     unx.push("123321").unwrap();
 
-    assert_eq!(unx.is_empty(), false);
+    assert!(!unx.is_empty());
     assert_eq!(unx.len(), 6);
     assert_eq!(unx.len_with_nul(), 7);
 }
@@ -31,3 +32,106 @@ fn len_and_cap() {
 
     assert_eq!(name.len(), unx.capacity());
 }
+
+#[test]
+#[cfg(feature = "std")]
+fn with_capacity_for() {
+    use std::path::PathBuf;
+
+    let path = PathBuf::from("/home/user");
+    let mut unx = UnixString::with_capacity_for(&path);
+
+    assert!(unx.is_empty());
+    assert_eq!(unx.len_with_nul(), 1);
+    assert!(unx.capacity() > path.as_os_str().len());
+
+    let capacity = unx.capacity();
+    unx.push(&path).unwrap();
+    assert_eq!(unx.capacity(), capacity);
+    assert_eq!(unx, path);
+
+    assert!(UnixString::with_capacity_for("").capacity() >= 1);
+}
+
+#[test]
+fn try_with_capacity() {
+    let unx = UnixString::try_with_capacity(49).unwrap();
+
+    assert!(unx.is_empty());
+    assert_eq!(unx.len_with_nul(), 1);
+    assert_eq!(unx.capacity(), 50);
+    assert!(unx.validate().is_ok());
+
+    assert_eq!(UnixString::try_with_capacity(0).unwrap().capacity(), 1);
+}
+
+#[test]
+#[should_panic(expected = "capacity overflow")]
+fn with_capacity_too_large() {
+    let _ = UnixString::with_capacity(usize::MAX);
+}
+
+#[test]
+fn try_with_capacity_too_large() {
+    use unixstring::Error;
+
+    for capacity in [usize::MAX, usize::MAX - 1, isize::MAX as usize] {
+        assert!(matches!(
+            UnixString::try_with_capacity(capacity),
+            Err(Error::Allocation(_))
+        ));
+    }
+}
+
+#[test]
+fn with_capacity_exact() {
+    let unx = UnixString::with_capacity_exact(49);
+
+    assert!(unx.is_empty());
+    assert_eq!(unx.len_with_nul(), 1);
+    assert_eq!(unx.capacity(), 50);
+    assert!(unx.validate().is_ok());
+
+    assert_eq!(UnixString::with_capacity_exact(0).capacity(), 1);
+}
+
+#[test]
+#[should_panic(expected = "capacity overflow")]
+fn with_capacity_exact_too_large() {
+    let _ = UnixString::with_capacity_exact(usize::MAX);
+}
+
+#[test]
+fn try_with_capacity_exact() {
+    use unixstring::Error;
+
+    let unx = UnixString::try_with_capacity_exact(49).unwrap();
+    assert!(unx.is_empty());
+    assert_eq!(unx.capacity(), 50);
+    assert!(unx.validate().is_ok());
+
+    for capacity in [usize::MAX, usize::MAX - 1, isize::MAX as usize] {
+        assert!(matches!(
+            UnixString::try_with_capacity_exact(capacity),
+            Err(Error::Allocation(_))
+        ));
+    }
+}
+
+#[test]
+#[cfg(feature = "std")]
+fn spare_capacity() {
+    // `with_capacity` always makes room for the nul terminator on top of the requested capacity
+    let mut unx = UnixString::with_capacity(50);
+    assert_eq!(unx.capacity(), 51);
+    assert_eq!(unx.spare_capacity(), 50);
+
+    unx.push("/home/user").unwrap();
+    assert_eq!(unx.spare_capacity(), 40);
+    assert_eq!(unx.spare_capacity(), unx.capacity() - unx.len_with_nul());
+
+    let full = UnixString::from_bytes(b"abc\0".to_vec()).unwrap();
+    assert_eq!(full.spare_capacity(), 0);
+
+    assert_eq!(UnixString::new().spare_capacity(), 0);
+}
@@ -1,10 +1,13 @@
+#[cfg(feature = "std")]
 use std::{ffi::OsStr, path::Path};
 
 use unixstring::UnixString;
 
 fn check_empty(empty: UnixString) {
     assert_eq!(empty.to_str().ok(), Some(""));
+    #[cfg(feature = "std")]
     assert_eq!(empty.as_os_str(), OsStr::new(""));
+    #[cfg(feature = "std")]
     assert_eq!(empty.as_path(), Path::new(""));
     assert_eq!(empty.as_bytes(), &[]);
     assert_eq!(empty.as_bytes_with_nul(), &[0]);
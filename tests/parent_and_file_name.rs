@@ -0,0 +1,102 @@
+use unixstring::UnixString;
+
+fn unx(path: &str) -> UnixString {
+    UnixString::from_string(path.into()).unwrap()
+}
+
+#[test]
+fn parent() {
+    assert_eq!(
+        unx("/home/user/file.txt").parent(),
+        Some(&b"/home/user"[..])
+    );
+    assert_eq!(unx("/home/user/").parent(), Some(&b"/home"[..]));
+    assert_eq!(unx("/home").parent(), Some(&b"/"[..]));
+    assert_eq!(unx("//home").parent(), Some(&b"/"[..]));
+    assert_eq!(unx("home/user").parent(), Some(&b"home"[..]));
+    assert_eq!(unx("home//user").parent(), Some(&b"home"[..]));
+    assert_eq!(unx("file").parent(), Some(&b""[..]));
+}
+
+#[test]
+fn parent_of_root_or_empty() {
+    assert_eq!(unx("/").parent(), None);
+    assert_eq!(unx("//").parent(), None);
+    assert_eq!(UnixString::new().parent(), None);
+}
+
+#[test]
+fn parent_of_trailing_cur_dir() {
+    assert_eq!(unx("a/.").parent(), Some(&b""[..]));
+    assert_eq!(unx("a/./").parent(), Some(&b""[..]));
+    assert_eq!(unx("a/./.").parent(), Some(&b""[..]));
+    assert_eq!(unx("/home/user/.").parent(), Some(&b"/home"[..]));
+    assert_eq!(unx("./a/.").parent(), Some(&b"."[..]));
+    assert_eq!(unx("/.").parent(), None);
+    assert_eq!(unx(".").parent(), Some(&b""[..]));
+    assert_eq!(unx("./.").parent(), Some(&b""[..]));
+}
+
+#[cfg(feature = "std")]
+#[test]
+fn parent_matches_path() {
+    use std::path::Path;
+
+    for path in [
+        "/home/user/file.txt",
+        "/home/user/",
+        "file",
+        "/",
+        "",
+        "a/.",
+        "a/./",
+        "/home/user/.",
+        "./a/.",
+        "/.",
+        ".",
+        "./.",
+        "a/..",
+    ] {
+        assert_eq!(
+            unx(path)
+                .parent()
+                .map(|parent| std::str::from_utf8(parent).unwrap()),
+            Path::new(path)
+                .parent()
+                .map(|parent| parent.to_str().unwrap()),
+            "parent of {:?}",
+            path
+        );
+    }
+}
+
+#[test]
+fn file_name() {
+    assert_eq!(
+        unx("/home/user/file.txt").file_name(),
+        Some(&b"file.txt"[..])
+    );
+    assert_eq!(unx("/home/user/").file_name(), Some(&b"user"[..]));
+    assert_eq!(unx("/home").file_name(), Some(&b"home"[..]));
+    assert_eq!(unx("file").file_name(), Some(&b"file"[..]));
+    assert_eq!(unx(".bashrc").file_name(), Some(&b".bashrc"[..]));
+}
+
+#[test]
+fn file_name_edge_cases() {
+    assert_eq!(unx("/").file_name(), None);
+    assert_eq!(unx("/home/..").file_name(), None);
+    assert_eq!(unx(".").file_name(), None);
+    assert_eq!(unx("a/.").file_name(), None);
+    assert_eq!(unx("/home/./").file_name(), None);
+    assert_eq!(unx("/.").file_name(), None);
+    assert_eq!(UnixString::new().file_name(), None);
+}
+
+#[test]
+fn non_utf8_path() {
+    let unx = UnixString::from_bytes(b"/tmp/\xFF\xFE".to_vec()).unwrap();
+
+    assert_eq!(unx.parent(), Some(&b"/tmp"[..]));
+    assert_eq!(unx.file_name(), Some(&b"\xFF\xFE"[..]));
+}
@@ -1,6 +1,9 @@
+use std::ffi::{CStr, CString};
+
+#[cfg(feature = "std")]
 use std::{
-    ffi::{CStr, CString, OsStr},
-    path::Path,
+    ffi::{OsStr, OsString},
+    path::{Path, PathBuf},
 };
 
 use unixstring::UnixString;
@@ -31,6 +34,7 @@ fn partial_eq_str() {
 }
 
 #[test]
+#[cfg(feature = "std")]
 fn partial_eq_os_str() {
     fn assert_equal(string: &OsStr, unix: UnixString) {
         assert!(string == unix);
@@ -81,6 +85,7 @@ fn partial_eq_c_str() {
 }
 
 #[test]
+#[cfg(feature = "std")]
 fn partial_eq_path() {
     fn assert_equal(string: &Path, unix: UnixString) {
         assert!(string == unix);
@@ -92,15 +97,107 @@ fn partial_eq_path() {
     let lipsum = Path::new("lorem ipsum");
     let lipsum_unx = UnixString::from_pathbuf(lipsum.into()).unwrap();
 
-    assert_equal(&lipsum, lipsum_unx);
+    assert_equal(lipsum, lipsum_unx);
 
     let empty_unx = UnixString::new();
     assert_equal(Path::new(""), empty_unx);
 
     let hello_world = Path::new("hello_world");
     let hello_worl_unx = UnixString::from_string("hello_worl".into()).unwrap();
-    assert!(&*hello_world != hello_worl_unx);
+    assert!(hello_world != hello_worl_unx);
 
     let hello_worldd_unx = UnixString::from_string("hello_worldd".into()).unwrap();
-    assert!(&*hello_world != hello_worldd_unx);
+    assert!(hello_world != hello_worldd_unx);
+}
+
+#[test]
+fn partial_eq_string() {
+    let lipsum = String::from("lorem ipsum");
+    let lipsum_unx = UnixString::from_string(lipsum.clone()).unwrap();
+
+    assert!(lipsum == lipsum_unx);
+    assert!(lipsum_unx == lipsum);
+    assert!(String::new() == UnixString::new());
+    let lorem = String::from("lorem");
+    let lipsumm = String::from("lorem ipsumm");
+    assert!(lorem != lipsum_unx);
+    assert!(lipsum_unx != lipsumm);
+}
+
+#[test]
+#[cfg(feature = "std")]
+fn partial_eq_os_string() {
+    let lipsum = OsString::from("lorem ipsum");
+    let lipsum_unx = UnixString::from_os_string(lipsum.clone()).unwrap();
+
+    assert!(lipsum == lipsum_unx);
+    assert!(lipsum_unx == lipsum);
+    assert!(OsString::new() == UnixString::new());
+    let lorem = OsString::from("lorem");
+    let lipsumm = OsString::from("lorem ipsumm");
+    assert!(lorem != lipsum_unx);
+    assert!(lipsum_unx != lipsumm);
+}
+
+#[test]
+fn partial_eq_c_string() {
+    let lipsum = CString::new("lorem ipsum").unwrap();
+    let lipsum_unx = UnixString::from_cstring(lipsum.clone());
+
+    assert!(lipsum == lipsum_unx);
+    assert!(lipsum_unx == lipsum);
+    assert!(CString::default() == UnixString::new());
+    assert!(CString::new("lorem").unwrap() != lipsum_unx);
+    assert!(lipsum_unx != CString::new("lorem ipsumm").unwrap());
+}
+
+#[test]
+#[cfg(feature = "std")]
+fn partial_eq_path_buf() {
+    let lipsum = PathBuf::from("lorem ipsum");
+    let lipsum_unx = UnixString::from_pathbuf(lipsum.clone()).unwrap();
+
+    assert!(lipsum == lipsum_unx);
+    assert!(lipsum_unx == lipsum);
+    assert!(PathBuf::new() == UnixString::new());
+    let lorem = PathBuf::from("lorem");
+    let lipsumm = PathBuf::from("lorem ipsumm");
+    assert!(lorem != lipsum_unx);
+    assert!(lipsum_unx != lipsumm);
+}
+
+#[test]
+#[allow(clippy::op_ref)]
+fn partial_eq_unix_string_ref() {
+    let lipsum = UnixString::from_string("lorem ipsum".into()).unwrap();
+    let other = lipsum.clone();
+
+    assert!(&lipsum == other);
+    assert!(other == &lipsum);
+    assert!(&lipsum != UnixString::new());
+}
+
+#[test]
+fn partial_eq_bytes() {
+    let unx = UnixString::from_bytes(b"/home/\xFFuser".to_vec()).unwrap();
+
+    assert_eq!(unx, b"/home/\xFFuser".to_vec());
+    assert_eq!(b"/home/\xFFuser".to_vec(), unx);
+    assert_eq!(unx, &b"/home/\xFFuser"[..]);
+    assert_eq!(&b"/home/\xFFuser"[..], unx);
+    assert_eq!(unx, *b"/home/\xFFuser");
+    assert_eq!(*b"/home/\xFFuser", unx);
+
+    // The nul terminator is not part of the comparison
+    assert_ne!(unx, b"/home/\xFFuser\0".to_vec());
+    assert_ne!(unx, &b"/home/\xFFuser\0"[..]);
+    assert_ne!(unx, *b"/home/\xFFuser\0");
+
+    assert_ne!(unx, b"/home/\xFFuse".to_vec());
+    assert_ne!(unx, &b"/home"[..]);
+
+    let empty = UnixString::new();
+    assert_eq!(empty, Vec::new());
+    assert_eq!(empty, &b""[..]);
+    assert_eq!(empty, []);
 }
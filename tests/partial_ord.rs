@@ -0,0 +1,81 @@
+use std::{cmp::Ordering, ffi::CStr};
+
+#[cfg(feature = "std")]
+use std::{ffi::OsStr, path::Path};
+
+use unixstring::UnixString;
+
+fn unx(text: &str) -> UnixString {
+    UnixString::from_string(text.into()).unwrap()
+}
+
+#[test]
+fn partial_ord_str() {
+    assert!(unx("abc") < "abd");
+    assert!(unx("abc") > "ab");
+    assert!(unx("abc") <= "abc");
+    assert!(unx("") < "a");
+
+    assert!("abd" > unx("abc"));
+    assert!("ab" < unx("abc"));
+    assert_eq!(
+        PartialOrd::partial_cmp(&"abc", &unx("abc")),
+        Some(Ordering::Equal)
+    );
+}
+
+#[test]
+#[cfg(feature = "std")]
+fn partial_ord_os_str() {
+    assert!(unx("abc") < OsStr::new("abd"));
+    assert!(unx("abc") > OsStr::new("ab"));
+    assert!(OsStr::new("abd") > unx("abc"));
+    assert_eq!(
+        unx("abc").partial_cmp(&OsStr::new("abc")),
+        Some(Ordering::Equal)
+    );
+}
+
+#[test]
+fn partial_ord_c_str() {
+    let abd = CStr::from_bytes_with_nul(b"abd\0").unwrap();
+    let ab = CStr::from_bytes_with_nul(b"ab\0").unwrap();
+
+    assert!(unx("abc") < abd);
+    assert!(unx("abc") > ab);
+    assert!(abd > unx("abc"));
+    assert!(ab < unx("abc"));
+}
+
+#[test]
+#[cfg(feature = "std")]
+fn partial_ord_path() {
+    assert!(unx("/home/a") < Path::new("/home/b"));
+    assert!(Path::new("/home/b") > unx("/home/a"));
+
+    // Consistent with `PartialEq<&Path>`
+    assert!(unx("/home//a") == Path::new("/home/a"));
+    assert_eq!(
+        unx("/home//a").partial_cmp(&Path::new("/home/a")),
+        Some(Ordering::Equal)
+    );
+}
+
+#[test]
+#[cfg(feature = "std")]
+fn consistent_with_ord() {
+    let words = ["", "a", "ab", "abc", "abd", "b", "ba", "\u{FF}"];
+
+    for left in &words {
+        for right in &words {
+            let expected = unx(left).cmp(&unx(right));
+
+            assert_eq!(unx(left).partial_cmp(right), Some(expected));
+            assert_eq!(unx(left).partial_cmp(&OsStr::new(right)), Some(expected));
+            assert_eq!(
+                unx(left).partial_cmp(&unx(right).as_c_str()),
+                Some(expected)
+            );
+        }
+    }
+}
@@ -0,0 +1,28 @@
+use unixstring::UnixString;
+
+#[test]
+fn pop() {
+    let mut unx = UnixString::from_string("ab/".into()).unwrap();
+    let capacity = unx.capacity();
+
+    assert_eq!(unx.pop(), Some(b'/'));
+    assert_eq!(unx.as_bytes_with_nul(), b"ab\0");
+    assert!(unx.validate().is_ok());
+
+    assert_eq!(unx.pop(), Some(b'b'));
+    assert_eq!(unx.pop(), Some(b'a'));
+    assert_eq!(unx.as_bytes_with_nul(), &[0]);
+    assert!(unx.is_empty());
+    assert!(unx.validate().is_ok());
+
+    assert_eq!(unx.capacity(), capacity);
+}
+
+#[test]
+fn pop_empty() {
+    let mut unx = UnixString::new();
+
+    assert_eq!(unx.pop(), None);
+    assert_eq!(unx.as_bytes_with_nul(), &[0]);
+    assert!(unx.validate().is_ok());
+}
@@ -0,0 +1,46 @@
+use unixstring::UnixString;
+
+fn pop(path: &[u8]) -> (bool, Vec<u8>) {
+    let mut unx = UnixString::from_bytes(path.to_vec()).unwrap();
+    let popped = unx.pop_path_component();
+    assert!(unx.validate().is_ok());
+
+    (popped, unx.into_bytes())
+}
+
+#[test]
+fn pop_path_component() {
+    assert_eq!(pop(b"/home/user"), (true, b"/home".to_vec()));
+    assert_eq!(pop(b"/home/user/"), (true, b"/home".to_vec()));
+    assert_eq!(pop(b"/home//user"), (true, b"/home".to_vec()));
+    assert_eq!(pop(b"home/user"), (true, b"home".to_vec()));
+    assert_eq!(pop(b"/home"), (true, b"/".to_vec()));
+    assert_eq!(pop(b"file"), (true, b"".to_vec()));
+}
+
+#[test]
+fn pop_path_component_trailing_cur_dir() {
+    assert_eq!(pop(b"a/."), (true, b"".to_vec()));
+    assert_eq!(pop(b"/home/user/."), (true, b"/home".to_vec()));
+    assert_eq!(pop(b"/."), (false, b"/.".to_vec()));
+}
+
+#[test]
+fn pop_path_component_nothing_to_pop() {
+    assert_eq!(pop(b"/"), (false, b"/".to_vec()));
+    assert_eq!(pop(b"//"), (false, b"//".to_vec()));
+    assert_eq!(pop(b""), (false, b"".to_vec()));
+}
+
+#[test]
+fn pop_path_component_to_root() {
+    let mut unx = UnixString::from_bytes(b"/usr/local/\xFFbin".to_vec()).unwrap();
+    let mut pops = 0;
+
+    while unx.pop_path_component() {
+        pops += 1;
+    }
+
+    assert_eq!(pops, 3);
+    assert_eq!(unx.as_bytes_with_nul(), b"/\0");
+}
@@ -0,0 +1,50 @@
+use unixstring::{Error, UnixString};
+
+#[test]
+fn prepend() {
+    let mut unx = UnixString::from_string("user/file".into()).unwrap();
+
+    unx.prepend("/home/").unwrap();
+    assert_eq!(unx.as_bytes_with_nul(), b"/home/user/file\0");
+
+    unx.prepend(b"/mnt\0").unwrap();
+    assert_eq!(unx.as_bytes_with_nul(), b"/mnt/home/user/file\0");
+
+    unx.prepend("").unwrap();
+    assert_eq!(unx.as_bytes_with_nul(), b"/mnt/home/user/file\0");
+
+    assert!(unx.validate().is_ok());
+}
+
+#[test]
+fn prepend_to_empty() {
+    let mut unx = UnixString::new();
+
+    unx.prepend(b"\xFFabc").unwrap();
+    assert_eq!(unx.as_bytes_with_nul(), b"\xFFabc\0");
+    assert!(unx.validate().is_ok());
+}
+
+#[test]
+fn prepend_long_prefix() {
+    let prefix = vec![b'a'; 4096];
+    let mut unx = UnixString::from_string("/file".into()).unwrap();
+
+    unx.prepend(&prefix).unwrap();
+
+    assert_eq!(unx.len(), 4096 + 5);
+    assert!(unx.capacity() >= unx.len_with_nul());
+    assert!(unx.as_bytes().starts_with(&prefix));
+    assert!(unx.validate().is_ok());
+}
+
+#[test]
+fn prepend_interior_nul() {
+    let mut unx = UnixString::from_string("file".into()).unwrap();
+
+    assert_eq!(
+        unx.prepend(b"/ho\0me/").unwrap_err(),
+        Error::InteriorNulByte { position: 3 }
+    );
+    assert_eq!(unx.as_bytes_with_nul(), b"file\0");
+}
@@ -1,12 +1,12 @@
-use std::{
-    convert::TryFrom,
-    ffi::{CStr, CString},
-    path::PathBuf,
-};
+use std::ffi::CString;
+
+#[cfg(feature = "std")]
+use std::{convert::TryFrom, ffi::CStr, path::PathBuf};
 
 use unixstring::UnixString;
 
 #[test]
+#[cfg(feature = "std")]
 fn as_ptr() {
     const HOME: &str = "/home/user";
     let home = PathBuf::from(HOME);
@@ -30,3 +30,82 @@ fn from_ptr() {
 
     assert_eq!(home.as_c_str(), unix_string.as_c_str())
 }
+
+#[test]
+fn from_ptr_with_len() {
+    // Not nul-terminated, just like what `readlink` writes
+    let buf = *b"/home/vrmiguel/.bashrc";
+
+    let unix_string = unsafe { UnixString::from_ptr_with_len(buf.as_ptr().cast(), 14) }.unwrap();
+
+    assert_eq!(unix_string.as_bytes(), b"/home/vrmiguel");
+    assert_eq!(unix_string.as_bytes_with_nul(), b"/home/vrmiguel\0");
+}
+
+#[test]
+fn from_ptr_with_len_empty() {
+    let buf = *b"abc";
+
+    let unix_string = unsafe { UnixString::from_ptr_with_len(buf.as_ptr().cast(), 0) }.unwrap();
+
+    assert!(unix_string.is_empty());
+    assert_eq!(unix_string.as_bytes_with_nul(), b"\0");
+}
+
+#[test]
+fn from_ptr_with_len_trailing_nul() {
+    let buf = *b"abc\0";
+
+    let unix_string = unsafe { UnixString::from_ptr_with_len(buf.as_ptr().cast(), 4) }.unwrap();
+
+    assert_eq!(unix_string.as_bytes_with_nul(), b"abc\0");
+}
+
+#[test]
+fn from_ptr_with_len_interior_nul() {
+    let buf = *b"ab\0cd";
+
+    let result = unsafe { UnixString::from_ptr_with_len(buf.as_ptr().cast(), 5) };
+
+    assert_eq!(
+        result.unwrap_err(),
+        unixstring::Error::InteriorNulByte { position: 2 }
+    );
+}
+
+#[test]
+fn as_ptr_and_len() {
+    let unx = UnixString::from_string("/home/user".into()).unwrap();
+
+    let (ptr, len) = unx.as_ptr_and_len();
+    assert_eq!(ptr, unx.as_ptr());
+    assert_eq!(len, unx.len());
+
+    let bytes = unsafe { std::slice::from_raw_parts(ptr.cast::<u8>(), len) };
+    assert_eq!(bytes, b"/home/user");
+
+    let (ptr, len) = unx.as_ptr_and_len_with_nul();
+    assert_eq!(ptr, unx.as_ptr());
+    assert_eq!(len, unx.len_with_nul());
+
+    let bytes = unsafe { std::slice::from_raw_parts(ptr.cast::<u8>(), len) };
+    assert_eq!(bytes, b"/home/user\0");
+}
+
+#[test]
+fn as_ptr_and_len_empty() {
+    let unx = UnixString::new();
+
+    assert_eq!(unx.as_ptr_and_len().1, 0);
+    assert_eq!(unx.as_ptr_and_len_with_nul().1, 1);
+}
+
+#[test]
+fn as_ptr_and_len_roundtrip() {
+    let unx = UnixString::from_string("/usr/bin".into()).unwrap();
+    let (ptr, len) = unx.as_ptr_and_len();
+
+    let copy = unsafe { UnixString::from_ptr_with_len(ptr, len) }.unwrap();
+
+    assert_eq!(copy, unx);
+}
@@ -0,0 +1,24 @@
+use unixstring::UnixString;
+
+#[test]
+fn as_ptr_range() {
+    let unx = UnixString::from_string("/home/user".into()).unwrap();
+    let range = unx.as_ptr_range();
+
+    assert_eq!(range.start, unx.as_ptr());
+    assert_eq!(range.end as usize - range.start as usize, unx.len());
+
+    // The end pointer points to the nul terminator
+    assert_eq!(unsafe { *range.end }, 0);
+    assert_eq!(unsafe { *range.end.sub(1) }, b'r' as _);
+}
+
+#[test]
+fn as_ptr_range_empty() {
+    let unx = UnixString::new();
+    let range = unx.as_ptr_range();
+
+    assert_eq!(range.start, range.end);
+    assert_eq!(range.start, unx.as_ptr());
+    assert_eq!(unsafe { *range.end }, 0);
+}
@@ -84,3 +84,42 @@ fn push_null_terminated_bytes() {
     unxstr.push_bytes(&cde).unwrap();
     assert_eq!(unxstr.as_bytes_with_nul(), &b"abcabccde\0".to_vec());
 }
+
+#[test]
+fn push_bytes_exact() {
+    let mut unx = UnixString::new();
+
+    unx.push_bytes_exact(b"/home").unwrap();
+    assert_eq!(unx.as_bytes_with_nul(), b"/home\0");
+    assert_eq!(unx.capacity(), unx.len_with_nul());
+
+    unx.push_bytes_exact(b"/user\0").unwrap();
+    assert_eq!(unx.as_bytes_with_nul(), b"/home/user\0");
+    assert_eq!(unx.capacity(), unx.len_with_nul());
+
+    unx.push_bytes_exact(b"").unwrap();
+    assert_eq!(unx.as_bytes_with_nul(), b"/home/user\0");
+    assert!(unx.validate().is_ok());
+}
+
+#[test]
+fn push_bytes_exact_with_spare_capacity() {
+    let mut unx = UnixString::with_capacity(64);
+
+    unx.push_bytes_exact(b"/home/user").unwrap();
+
+    assert_eq!(unx.capacity(), 65);
+}
+
+#[test]
+fn push_bytes_exact_interior_nul() {
+    let mut unx = UnixString::from_bytes(b"/home".to_vec()).unwrap();
+    let capacity = unx.capacity();
+
+    assert_eq!(
+        unx.push_bytes_exact(b"/us\0er").unwrap_err(),
+        unixstring::Error::InteriorNulByte { position: 3 }
+    );
+    assert_eq!(unx.as_bytes_with_nul(), b"/home\0");
+    assert_eq!(unx.capacity(), capacity);
+}
@@ -1,3 +1,5 @@
+#![cfg(feature = "std")]
+
 use unixstring::UnixString;
 
 #[test]
@@ -59,3 +61,32 @@ fn push_str() {
     assert_eq!(unx.as_bytes_with_nul(), b"abcd\0".to_vec());
     assert_eq!(unx.to_str().unwrap(), "abcd");
 }
+
+#[test]
+fn extend_from_os_str() {
+    use std::{
+        ffi::{OsStr, OsString},
+        path::{Path, PathBuf},
+    };
+
+    let mut unx = UnixString::new();
+
+    unx.extend_from_os_str(Path::new("/home")).unwrap();
+    unx.extend_from_os_str(PathBuf::from("/user")).unwrap();
+    unx.extend_from_os_str(OsString::from("/.config")).unwrap();
+    unx.extend_from_os_str(OsStr::new("/nvim\0")).unwrap();
+
+    assert_eq!(unx.as_bytes_with_nul(), b"/home/user/.config/nvim\0");
+    assert!(unx.validate().is_ok());
+}
+
+#[test]
+fn extend_from_os_str_interior_nul() {
+    let mut unx = UnixString::from_string("/home".into()).unwrap();
+
+    assert_eq!(
+        unx.extend_from_os_str("/us\0er").unwrap_err(),
+        unixstring::Error::InteriorNulByte { position: 3 }
+    );
+    assert_eq!(unx.as_bytes_with_nul(), b"/home\0");
+}
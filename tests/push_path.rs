@@ -0,0 +1,124 @@
+#![cfg(feature = "std")]
+
+use std::path::{Path, PathBuf};
+
+use unixstring::{Error, UnixString};
+
+fn assert_same_as_path_buf(base: &str, component: &str) {
+    let mut unx = UnixString::from_string(base.into()).unwrap();
+    let mut path_buf = PathBuf::from(base);
+
+    unx.push_path(component).unwrap();
+    path_buf.push(component);
+
+    assert_eq!(unx, path_buf);
+    assert!(unx.validate().is_ok());
+}
+
+#[test]
+fn push_path() {
+    let mut unx = UnixString::from_string("/home".into()).unwrap();
+
+    unx.push_path("user").unwrap();
+    assert_eq!(unx.as_bytes_with_nul(), b"/home/user\0");
+
+    unx.push_path(Path::new(".config/nvim")).unwrap();
+    assert_eq!(unx.as_bytes_with_nul(), b"/home/user/.config/nvim\0");
+}
+
+#[test]
+fn push_path_matches_path_buf() {
+    assert_same_as_path_buf("/home", "user");
+    assert_same_as_path_buf("/home/", "user");
+    assert_same_as_path_buf("/", "etc");
+    assert_same_as_path_buf("", "etc");
+    assert_same_as_path_buf("relative", "path");
+    assert_same_as_path_buf("/home", "/etc/passwd");
+    assert_same_as_path_buf("/home", "");
+}
+
+#[test]
+fn push_path_absolute_replaces() {
+    let mut unx = UnixString::from_string("/home/user".into()).unwrap();
+
+    unx.push_path("/etc").unwrap();
+
+    assert_eq!(unx.as_bytes_with_nul(), b"/etc\0");
+}
+
+#[test]
+fn push_path_with_nul_terminator() {
+    let mut unx = UnixString::from_string("/home".into()).unwrap();
+
+    unx.push_path("user\0").unwrap();
+
+    assert_eq!(unx.as_bytes_with_nul(), b"/home/user\0");
+}
+
+#[test]
+fn push_path_interior_nul_leaves_unchanged() {
+    let mut unx = UnixString::from_string("/home".into()).unwrap();
+
+    assert_eq!(
+        unx.push_path("us\0er").unwrap_err(),
+        Error::InteriorNulByte { position: 2 }
+    );
+    assert_eq!(
+        unx.push_path("/etc\0passwd").unwrap_err(),
+        Error::InteriorNulByte { position: 4 }
+    );
+    assert_eq!(unx.as_bytes_with_nul(), b"/home\0");
+}
+
+#[test]
+fn join_all() {
+    let unx = UnixString::join_all(["/home", "user", "file"]).unwrap();
+    assert_eq!(unx.as_bytes_with_nul(), b"/home/user/file\0");
+    assert!(unx.validate().is_ok());
+
+    let unx = UnixString::join_all(vec![PathBuf::from("usr/"), PathBuf::from("lib")]).unwrap();
+    assert_eq!(unx.as_bytes_with_nul(), b"usr/lib\0");
+}
+
+#[test]
+fn join_all_matches_path_buf() {
+    let parts = [
+        vec!["/home", "user", "file"],
+        vec!["/home", "user", "/etc", "passwd"],
+        vec!["relative", "path/", "file"],
+        vec!["", "a"],
+        vec!["/"],
+    ];
+
+    for parts in parts.iter() {
+        let unx = UnixString::join_all(parts).unwrap();
+        let path_buf: PathBuf = parts.iter().collect();
+
+        assert_eq!(unx, path_buf);
+    }
+}
+
+#[test]
+fn join_all_empty() {
+    let unx = UnixString::join_all(Vec::<&str>::new()).unwrap();
+
+    assert!(unx.is_empty());
+    assert!(unx.validate().is_ok());
+}
+
+#[test]
+fn join_all_lazy_iterator() {
+    let unx = UnixString::join_all((0..1000).map(|_| "ab")).unwrap();
+
+    assert_eq!(unx.len(), 1000 * 3 - 1);
+    assert!(unx.as_bytes().starts_with(b"ab/ab/"));
+    assert!(unx.validate().is_ok());
+}
+
+#[test]
+fn join_all_interior_nul() {
+    assert_eq!(
+        UnixString::join_all(["/home", "us\0er"]).unwrap_err(),
+        Error::InteriorNulByte { position: 2 }
+    );
+}
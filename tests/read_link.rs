@@ -0,0 +1,53 @@
+#![cfg(feature = "std")]
+
+use std::{fs, os::unix::fs::symlink, path::PathBuf};
+
+use unixstring::UnixString;
+
+fn scratch_dir(name: &str) -> PathBuf {
+    let dir = std::env::temp_dir().join(format!("unixstring-{}-{}", name, std::process::id()));
+    let _ = fs::remove_dir_all(&dir);
+    fs::create_dir_all(&dir).unwrap();
+    dir
+}
+
+#[test]
+fn read_link() {
+    let dir = scratch_dir("read-link");
+    let link = dir.join("link");
+    symlink("/home/user/.bashrc", &link).unwrap();
+
+    let link = UnixString::from_pathbuf(link).unwrap();
+    let target = link.read_link().unwrap();
+
+    assert_eq!(target, "/home/user/.bashrc");
+    assert_eq!(target.as_bytes_with_nul(), b"/home/user/.bashrc\0");
+
+    fs::remove_dir_all(&dir).unwrap();
+}
+
+#[test]
+fn read_link_long_target() {
+    let dir = scratch_dir("read-link-long");
+    let link = dir.join("link");
+    let target = "a/".repeat(1000) + "file";
+    symlink(&target, &link).unwrap();
+
+    let link = UnixString::from_pathbuf(link).unwrap();
+
+    assert_eq!(link.read_link().unwrap(), target.as_str());
+
+    fs::remove_dir_all(&dir).unwrap();
+}
+
+#[test]
+fn read_link_not_a_link() {
+    let dir = scratch_dir("read-link-not-a-link");
+
+    let dir_unx = UnixString::from_pathbuf(dir.clone()).unwrap();
+    let err = dir_unx.read_link().unwrap_err();
+
+    assert_eq!(err.raw_os_error(), Some(libc::EINVAL));
+
+    fs::remove_dir_all(&dir).unwrap();
+}
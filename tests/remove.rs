@@ -0,0 +1,34 @@
+use unixstring::UnixString;
+
+#[test]
+fn remove() {
+    let mut unx = UnixString::from_string("abcd".into()).unwrap();
+
+    assert_eq!(unx.remove(1), b'b');
+    assert_eq!(unx.as_bytes_with_nul(), b"acd\0");
+    assert!(unx.validate().is_ok());
+
+    assert_eq!(unx.remove(2), b'd');
+    assert_eq!(unx.as_bytes_with_nul(), b"ac\0");
+
+    assert_eq!(unx.remove(0), b'a');
+    assert_eq!(unx.remove(0), b'c');
+    assert!(unx.is_empty());
+    assert!(unx.validate().is_ok());
+}
+
+#[test]
+#[should_panic]
+fn remove_terminator() {
+    let mut unx = UnixString::from_string("abcd".into()).unwrap();
+
+    unx.remove(4);
+}
+
+#[test]
+#[should_panic]
+fn remove_empty() {
+    let mut unx = UnixString::new();
+
+    unx.remove(0);
+}
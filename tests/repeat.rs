@@ -0,0 +1,38 @@
+use unixstring::UnixString;
+
+#[test]
+fn repeat() {
+    let unx = UnixString::from_string("../".into()).unwrap();
+
+    let repeated = unx.repeat(3);
+    assert_eq!(repeated.as_bytes_with_nul(), b"../../../\0");
+    assert!(repeated.validate().is_ok());
+
+    assert_eq!(unx.repeat(1), unx);
+}
+
+#[test]
+fn repeat_zero_times() {
+    let unx = UnixString::from_string("abc".into()).unwrap();
+
+    let repeated = unx.repeat(0);
+    assert!(repeated.is_empty());
+    assert!(repeated.validate().is_ok());
+}
+
+#[test]
+fn repeat_empty() {
+    let repeated = UnixString::new().repeat(100);
+
+    assert!(repeated.is_empty());
+    assert_eq!(repeated.as_bytes_with_nul(), b"\0");
+}
+
+#[test]
+fn repeat_large() {
+    let unx = UnixString::from_string("a".into()).unwrap();
+
+    let repeated = unx.repeat(1 << 16);
+    assert_eq!(repeated.len(), 1 << 16);
+    assert!(repeated.validate().is_ok());
+}
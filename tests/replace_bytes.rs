@@ -0,0 +1,62 @@
+use unixstring::{Error, UnixString};
+
+#[test]
+fn replace_bytes() {
+    let unx = UnixString::from_string("/mnt/old/a:/mnt/old/b".into()).unwrap();
+
+    let replaced = unx.replace_bytes(b"/mnt/old", b"/media/new").unwrap();
+    assert_eq!(replaced.as_bytes_with_nul(), b"/media/new/a:/media/new/b\0");
+    assert!(replaced.validate().is_ok());
+
+    let replaced = unx.replace_bytes(b":", b"").unwrap();
+    assert_eq!(replaced.as_bytes_with_nul(), b"/mnt/old/a/mnt/old/b\0");
+
+    let replaced = unx.replace_bytes(b"/", b"//").unwrap();
+    assert_eq!(
+        replaced.as_bytes_with_nul(),
+        b"//mnt//old//a://mnt//old//b\0"
+    );
+}
+
+#[test]
+fn replace_bytes_non_overlapping() {
+    let unx = UnixString::from_string("aaaaa".into()).unwrap();
+
+    let replaced = unx.replace_bytes(b"aa", b"b").unwrap();
+
+    assert_eq!(replaced.as_bytes_with_nul(), b"bba\0");
+}
+
+#[test]
+fn replace_bytes_no_match() {
+    let unx = UnixString::from_string("/home/user".into()).unwrap();
+
+    assert_eq!(unx.replace_bytes(b"/root", b"/home").unwrap(), unx);
+    assert_eq!(unx.replace_bytes(b"/home/user/", b"x").unwrap(), unx);
+    assert_eq!(unx.replace_bytes(b"\0", b"x").unwrap(), unx);
+}
+
+#[test]
+fn replace_bytes_empty_from() {
+    let unx = UnixString::from_string("/home/user".into()).unwrap();
+
+    assert_eq!(unx.replace_bytes(b"", b"x").unwrap(), unx);
+    assert_eq!(
+        UnixString::new().replace_bytes(b"", b"x").unwrap(),
+        UnixString::new()
+    );
+}
+
+#[test]
+fn replace_bytes_rejects_nul_in_replacement() {
+    let unx = UnixString::from_string("/home/user".into()).unwrap();
+
+    assert_eq!(
+        unx.replace_bytes(b"home", b"ho\0me").unwrap_err(),
+        Error::InteriorNulByte { position: 2 }
+    );
+    assert_eq!(
+        unx.replace_bytes(b"home", b"home\0").unwrap_err(),
+        Error::InteriorNulByte { position: 4 }
+    );
+}
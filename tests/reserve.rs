@@ -0,0 +1,77 @@
+use unixstring::UnixString;
+
+#[test]
+#[cfg(feature = "std")]
+fn reserve() {
+    let mut unx = UnixString::new();
+    unx.reserve(10);
+
+    assert!(unx.capacity() >= unx.len_with_nul() + 10);
+    assert!(unx.validate().is_ok());
+
+    unx.push("/home/user").unwrap();
+    unx.reserve(100);
+
+    assert!(unx.capacity() >= unx.len_with_nul() + 100);
+    assert_eq!(unx.to_str().unwrap(), "/home/user");
+}
+
+#[test]
+#[cfg(feature = "std")]
+fn reserve_exact() {
+    let mut unx = UnixString::new();
+    unx.reserve_exact(10);
+
+    assert!(unx.capacity() >= 11);
+    assert!(unx.validate().is_ok());
+
+    unx.push("0123456789").unwrap();
+
+    assert_eq!(unx.len_with_nul(), 11);
+    assert!(unx.capacity() >= unx.len_with_nul());
+}
+
+#[test]
+fn reserve_does_not_shrink() {
+    let mut unx = UnixString::with_capacity(64);
+    unx.reserve(1);
+    unx.reserve_exact(1);
+
+    assert_eq!(unx.capacity(), 65);
+}
+
+#[test]
+#[cfg(feature = "std")]
+fn try_reserve() {
+    let mut unx = UnixString::new();
+    unx.try_reserve(10).unwrap();
+
+    assert!(unx.capacity() >= unx.len_with_nul() + 10);
+    assert!(unx.validate().is_ok());
+
+    unx.push("/home/user").unwrap();
+    unx.try_reserve(100).unwrap();
+
+    assert!(unx.capacity() >= unx.len_with_nul() + 100);
+    assert_eq!(unx.to_str().unwrap(), "/home/user");
+}
+
+#[test]
+fn try_reserve_absurd_capacity() {
+    let mut unx = UnixString::from_string("/home/user".into()).unwrap();
+    let capacity = unx.capacity();
+
+    for additional in [
+        usize::MAX,
+        usize::MAX - unx.len_with_nul(),
+        isize::MAX as usize,
+    ] {
+        assert!(matches!(
+            unx.try_reserve(additional),
+            Err(unixstring::Error::Allocation(_))
+        ));
+    }
+
+    assert_eq!(unx.capacity(), capacity);
+    assert_eq!(unx.as_bytes_with_nul(), b"/home/user\0");
+}
@@ -0,0 +1,43 @@
+use unixstring::{Error, UnixString};
+
+#[test]
+fn resize_grows() {
+    let mut unx = UnixString::from_string("abc".into()).unwrap();
+
+    unx.resize(6, b' ').unwrap();
+    assert_eq!(unx.as_bytes_with_nul(), b"abc   \0");
+    assert!(unx.validate().is_ok());
+
+    let mut empty = UnixString::new();
+    empty.resize(3, 0xFF).unwrap();
+    assert_eq!(empty.as_bytes_with_nul(), b"\xFF\xFF\xFF\0");
+    assert!(empty.validate().is_ok());
+}
+
+#[test]
+fn resize_truncates() {
+    let mut unx = UnixString::from_string("/home/user".into()).unwrap();
+
+    unx.resize(5, b' ').unwrap();
+    assert_eq!(unx.as_bytes_with_nul(), b"/home\0");
+    assert!(unx.validate().is_ok());
+
+    // A zero fill byte is fine as long as it's not used
+    unx.resize(5, 0).unwrap();
+    assert_eq!(unx.as_bytes_with_nul(), b"/home\0");
+
+    unx.resize(0, 0).unwrap();
+    assert!(unx.is_empty());
+    assert!(unx.validate().is_ok());
+}
+
+#[test]
+fn resize_zero_fill() {
+    let mut unx = UnixString::from_string("abc".into()).unwrap();
+
+    assert_eq!(
+        unx.resize(5, 0).unwrap_err(),
+        Error::InteriorNulByte { position: 3 }
+    );
+    assert_eq!(unx.as_bytes_with_nul(), b"abc\0");
+}
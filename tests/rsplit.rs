@@ -0,0 +1,42 @@
+use unixstring::UnixString;
+
+fn rsplit(unx: &UnixString, delimiter: u8) -> Vec<&[u8]> {
+    unx.rsplit(delimiter).collect()
+}
+
+#[test]
+fn rfind() {
+    let unx = UnixString::from_string("/home/user/archive.tar.gz".into()).unwrap();
+
+    assert_eq!(unx.rfind(b'.'), Some(22));
+    assert_eq!(unx.rfind(b'/'), Some(10));
+    assert_eq!(unx.rfind(b'm'), Some(3));
+    assert_eq!(unx.rfind(b'x'), None);
+}
+
+#[test]
+fn rfind_never_matches_the_nul_terminator() {
+    let unx = UnixString::from_string("abc".into()).unwrap();
+    assert_eq!(unx.rfind(0), None);
+
+    let empty = UnixString::new();
+    assert_eq!(empty.rfind(0), None);
+}
+
+#[test]
+fn rsplit_matches_slice_rsplit() {
+    for text in &["", ":", "::", "a", ":a", "a:", "a::b", ":a:b:", "abc"] {
+        let unx = UnixString::from_string(text.to_string()).unwrap();
+        let expected: Vec<&[u8]> = text.as_bytes().rsplit(|&b| b == b':').collect();
+
+        assert_eq!(rsplit(&unx, b':'), expected);
+    }
+}
+
+#[test]
+fn rsplit_extension() {
+    let unx = UnixString::from_string("archive.tar.gz".into()).unwrap();
+
+    assert_eq!(rsplit(&unx, b'.'), [&b"gz"[..], b"tar", b"archive"]);
+    assert_eq!(rsplit(&unx, 0), [&b"archive.tar.gz"[..]]);
+}
@@ -0,0 +1,68 @@
+#![cfg(feature = "serde")]
+
+use serde_test::{assert_de_tokens, assert_de_tokens_error, assert_tokens, Token};
+use unixstring::UnixString;
+
+#[test]
+fn serde_round_trip() {
+    let unx = UnixString::from_string("/home/user".into()).unwrap();
+
+    assert_tokens(&unx, &[Token::Bytes(b"/home/user")]);
+}
+
+#[test]
+fn serde_round_trip_non_utf8() {
+    let unx = UnixString::from_bytes(vec![b'/', 0xFF, 0xFE]).unwrap();
+
+    assert_tokens(&unx, &[Token::Bytes(&[b'/', 0xFF, 0xFE])]);
+}
+
+#[test]
+fn serde_round_trip_empty() {
+    assert_tokens(&UnixString::new(), &[Token::Bytes(b"")]);
+}
+
+#[test]
+fn deserialize_from_other_forms() {
+    let unx = UnixString::from_string("abc".into()).unwrap();
+
+    assert_de_tokens(&unx, &[Token::ByteBuf(b"abc")]);
+    assert_de_tokens(&unx, &[Token::Str("abc")]);
+    assert_de_tokens(&unx, &[Token::String("abc")]);
+    assert_de_tokens(
+        &unx,
+        &[
+            Token::Seq { len: Some(3) },
+            Token::U8(b'a'),
+            Token::U8(b'b'),
+            Token::U8(b'c'),
+            Token::SeqEnd,
+        ],
+    );
+}
+
+#[test]
+fn deserialize_fails_with_interior_nul() {
+    assert_de_tokens_error::<UnixString>(
+        &[Token::Bytes(b"a\0bc")],
+        "Interior zero byte found at position 1 during CString construction",
+    );
+}
+
+#[test]
+fn deserialize_seq_with_untrusted_size_hint() {
+    let unx = UnixString::from_string("ab".into()).unwrap();
+
+    // A huge size hint must neither overflow nor be preallocated
+    assert_de_tokens(
+        &unx,
+        &[
+            Token::Seq {
+                len: Some(usize::MAX),
+            },
+            Token::U8(b'a'),
+            Token::U8(b'b'),
+            Token::SeqEnd,
+        ],
+    );
+}
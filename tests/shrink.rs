@@ -0,0 +1,79 @@
+use unixstring::UnixString;
+
+#[test]
+#[cfg(feature = "std")]
+fn shrink_to_fit() {
+    let mut unx = UnixString::with_capacity(1024);
+    assert_eq!(unx.capacity(), 1025);
+
+    unx.push("/home/user").unwrap();
+    unx.shrink_to_fit();
+
+    assert!(unx.capacity() < 1025);
+    assert!(unx.capacity() >= unx.len_with_nul());
+    assert!(unx.validate().is_ok());
+    assert_eq!(unx.to_str().unwrap(), "/home/user");
+}
+
+#[test]
+fn shrink_to_fit_after_ffi_write() {
+    let mut unx = UnixString::with_capacity(1024);
+
+    let ptr = unx.as_mut_ptr();
+    for (idx, &byte) in b"/tmp\0".iter().enumerate() {
+        unsafe {
+            ptr.add(idx).write(byte as _);
+        }
+    }
+    unsafe {
+        unx.set_len(5);
+    }
+
+    unx.shrink_to_fit();
+
+    assert!(unx.capacity() < 1025);
+    assert!(unx.validate().is_ok());
+    assert_eq!(unx.as_bytes_with_nul(), b"/tmp\0");
+}
+
+#[test]
+fn shrink_empty() {
+    let mut unx = UnixString::with_capacity(1024);
+    unx.shrink_to_fit();
+
+    assert!(unx.capacity() >= 1);
+    assert!(unx.validate().is_ok());
+}
+
+#[test]
+#[cfg(feature = "std")]
+fn shrink_to() {
+    let mut unx = UnixString::with_capacity(1024);
+    unx.push("/home/user").unwrap();
+
+    unx.shrink_to(100);
+    assert!(unx.capacity() >= 101);
+    assert!(unx.capacity() < 1025);
+    assert!(unx.validate().is_ok());
+
+    // Growing is never done by `shrink_to`
+    let capacity = unx.capacity();
+    unx.shrink_to(4096);
+    assert_eq!(unx.capacity(), capacity);
+
+    // The contents and their nul terminator are always kept
+    unx.shrink_to(0);
+    assert!(unx.capacity() >= unx.len_with_nul());
+    assert!(unx.validate().is_ok());
+    assert_eq!(unx.as_bytes_with_nul(), b"/home/user\0");
+}
+
+#[test]
+fn shrink_to_max() {
+    let mut unx = UnixString::with_capacity(16);
+
+    unx.shrink_to(usize::MAX);
+
+    assert_eq!(unx.capacity(), 17);
+    assert!(unx.validate().is_ok());
+}
@@ -0,0 +1,86 @@
+use unixstring::UnixString;
+
+fn split(unx: &UnixString, delimiter: u8) -> Vec<&[u8]> {
+    unx.split(delimiter).collect()
+}
+
+#[test]
+fn split_path_variable() {
+    let unx = UnixString::from_string("/bin:/usr/bin:/usr/local/bin".into()).unwrap();
+
+    assert_eq!(
+        split(&unx, b':'),
+        [&b"/bin"[..], b"/usr/bin", b"/usr/local/bin"]
+    );
+}
+
+#[test]
+fn split_matches_slice_split() {
+    for text in &["", ":", "::", "a", ":a", "a:", "a::b", ":a:b:", "abc"] {
+        let unx = UnixString::from_string(text.to_string()).unwrap();
+        let expected: Vec<&[u8]> = text.as_bytes().split(|&b| b == b':').collect();
+
+        assert_eq!(split(&unx, b':'), expected);
+    }
+}
+
+#[test]
+fn split_empty() {
+    let unx = UnixString::new();
+
+    assert_eq!(split(&unx, b':'), [&b""[..]]);
+}
+
+#[test]
+fn split_never_includes_the_nul_terminator() {
+    let unx = UnixString::from_string("a:b".into()).unwrap();
+
+    assert_eq!(split(&unx, 0), [&b"a:b"[..]]);
+    assert_eq!(split(&unx, b':'), [&b"a"[..], b"b"]);
+}
+
+#[test]
+fn split_once() {
+    let unx = UnixString::from_string("KEY=VALUE=MORE".into()).unwrap();
+
+    assert_eq!(
+        unx.split_once(b'='),
+        Some((&b"KEY"[..], &b"VALUE=MORE"[..]))
+    );
+    assert_eq!(
+        unx.rsplit_once(b'='),
+        Some((&b"KEY=VALUE"[..], &b"MORE"[..]))
+    );
+    assert_eq!(unx.split_once(b'#'), None);
+    assert_eq!(unx.rsplit_once(b'#'), None);
+    // The nul terminator is never matched
+    assert_eq!(unx.split_once(0), None);
+}
+
+#[test]
+fn split_once_at_edges() {
+    let unx = UnixString::from_string("=".into()).unwrap();
+    assert_eq!(unx.split_once(b'='), Some((&b""[..], &b""[..])));
+    assert_eq!(unx.rsplit_once(b'='), Some((&b""[..], &b""[..])));
+
+    let unx = UnixString::from_string("=a=".into()).unwrap();
+    assert_eq!(unx.split_once(b'='), Some((&b""[..], &b"a="[..])));
+    assert_eq!(unx.rsplit_once(b'='), Some((&b"=a"[..], &b""[..])));
+
+    assert_eq!(UnixString::new().split_once(b'='), None);
+    assert_eq!(UnixString::new().rsplit_once(b'='), None);
+}
+
+#[test]
+fn split_once_matches_str_split_once() {
+    fn as_bytes<'a>((before, after): (&'a str, &'a str)) -> (&'a [u8], &'a [u8]) {
+        (before.as_bytes(), after.as_bytes())
+    }
+
+    for text in &["", "=", "==", "a", "=a", "a=", "a==b", "=a=b=", "abc"] {
+        let unx = UnixString::from_string(text.to_string()).unwrap();
+
+        assert_eq!(unx.split_once(b'='), text.split_once('=').map(as_bytes));
+        assert_eq!(unx.rsplit_once(b'='), text.rsplit_once('=').map(as_bytes));
+    }
+}
@@ -0,0 +1,25 @@
+use unixstring::UnixString;
+
+#[test]
+fn split_at() {
+    let unx = UnixString::from_string("/home/user".into()).unwrap();
+
+    assert_eq!(unx.split_at(5), (&b"/home"[..], &b"/user"[..]));
+    assert_eq!(unx.split_at(0), (&b""[..], &b"/home/user"[..]));
+    assert_eq!(unx.split_at(unx.len()), (&b"/home/user"[..], &b""[..]));
+}
+
+#[test]
+fn split_at_empty() {
+    let unx = UnixString::new();
+
+    assert_eq!(unx.split_at(0), (&b""[..], &b""[..]));
+}
+
+#[test]
+#[should_panic]
+fn split_at_nul_terminator() {
+    let unx = UnixString::from_string("/home/user".into()).unwrap();
+
+    unx.split_at(unx.len_with_nul());
+}
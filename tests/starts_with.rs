@@ -1,3 +1,5 @@
+#![cfg(feature = "std")]
+
 use unixstring::{Result, UnixString};
 
 #[test]
@@ -38,3 +40,47 @@ pub fn starts_with_empty() -> Result<()> {
 
     Ok(())
 }
+
+#[test]
+pub fn strip_prefix() -> Result<()> {
+    let unix_string = UnixString::from_string("/home/user/.bashrc".into())?;
+
+    assert_eq!(
+        unix_string.strip_prefix("/home/user/"),
+        Some(&b".bashrc"[..])
+    );
+    assert_eq!(
+        unix_string.strip_prefix("/home/user"),
+        Some(&b"/.bashrc"[..])
+    );
+    assert_eq!(unix_string.strip_prefix(""), Some(unix_string.as_bytes()));
+    assert_eq!(
+        unix_string.strip_prefix("/home/user/.bashrc"),
+        Some(&b""[..])
+    );
+
+    assert_eq!(unix_string.strip_prefix("/root"), None);
+    assert_eq!(unix_string.strip_prefix("/home/user/.bashrc/"), None);
+    // The nul terminator is never matched
+    assert_eq!(unix_string.strip_prefix("/home/user/.bashrc\0"), None);
+
+    Ok(())
+}
+
+#[test]
+pub fn strip_prefix_non_utf8() -> Result<()> {
+    use std::{ffi::OsStr, os::unix::ffi::OsStrExt};
+
+    let unix_string = UnixString::from_bytes(b"/tmp/\xff\xfe/file".to_vec())?;
+
+    assert_eq!(
+        unix_string.strip_prefix(OsStr::from_bytes(b"/tmp/\xff\xfe/")),
+        Some(&b"file"[..])
+    );
+    assert_eq!(
+        unix_string.strip_prefix(OsStr::from_bytes(b"/tmp/\xff\xff")),
+        None
+    );
+
+    Ok(())
+}
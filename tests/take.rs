@@ -0,0 +1,31 @@
+use unixstring::UnixString;
+
+#[test]
+#[cfg(feature = "std")]
+fn take() {
+    let mut unx = UnixString::with_capacity(32);
+    unx.push("/home/user/file.txt").unwrap();
+    let capacity = unx.capacity();
+
+    let taken = unx.take();
+
+    assert_eq!(taken.as_bytes_with_nul(), b"/home/user/file.txt\0");
+    assert_eq!(taken.capacity(), capacity);
+    assert!(taken.validate().is_ok());
+
+    assert!(unx.is_empty());
+    assert_eq!(unx.as_bytes_with_nul(), &[0]);
+    assert!(unx.validate().is_ok());
+
+    unx.push("/tmp").unwrap();
+    assert_eq!(unx.as_bytes_with_nul(), b"/tmp\0");
+}
+
+#[test]
+fn take_empty() {
+    let mut unx = UnixString::new();
+
+    assert!(unx.take().is_empty());
+    assert!(unx.is_empty());
+    assert!(unx.validate().is_ok());
+}
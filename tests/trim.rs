@@ -0,0 +1,41 @@
+use unixstring::UnixString;
+
+#[test]
+fn trimmed_end() {
+    let unx = UnixString::from_string("/home/user///".into()).unwrap();
+
+    assert_eq!(unx.trimmed_end(b'/'), b"/home/user");
+    assert_eq!(unx.trimmed_end(b'r'), b"/home/user///");
+    assert_eq!(unx.trimmed_end(0), b"/home/user///");
+}
+
+#[test]
+fn trim_end_matches() {
+    let mut unx = UnixString::from_string("/home/user///".into()).unwrap();
+
+    unx.trim_end_matches(b'/');
+    assert_eq!(unx.as_bytes_with_nul(), b"/home/user\0");
+
+    unx.trim_end_matches(b'/');
+    assert_eq!(unx.as_bytes_with_nul(), b"/home/user\0");
+
+    unx.trim_end_matches(b'r');
+    assert_eq!(unx.as_bytes_with_nul(), b"/home/use\0");
+    assert!(unx.validate().is_ok());
+}
+
+#[test]
+fn trim_end_matches_everything() {
+    let mut unx = UnixString::from_string("////".into()).unwrap();
+
+    assert_eq!(unx.trimmed_end(b'/'), b"");
+
+    unx.trim_end_matches(b'/');
+    assert!(unx.is_empty());
+    assert_eq!(unx.as_bytes_with_nul(), b"\0");
+    assert!(unx.validate().is_ok());
+
+    let mut empty = UnixString::new();
+    empty.trim_end_matches(b'/');
+    assert_eq!(empty.as_bytes_with_nul(), b"\0");
+}
@@ -19,7 +19,7 @@ fn size_one_vec() {
 
     assert_eq!(one.to_str().unwrap(), "1");
 
-    assert_eq!(one.as_bytes(), &[b'1']);
+    assert_eq!(one.as_bytes(), b"1");
 
     assert_eq!(one.as_bytes_with_nul(), &[b'1', 0]);
 }
@@ -0,0 +1,45 @@
+use std::convert::TryFrom;
+
+use unixstring::{Error, UnixString};
+
+#[test]
+fn try_from_char() {
+    let unx = UnixString::try_from('a').unwrap();
+    assert_eq!(unx.as_bytes_with_nul(), b"a\0");
+
+    let unx = UnixString::try_from('é').unwrap();
+    assert_eq!(unx.as_bytes(), "é".as_bytes());
+    assert_eq!(unx.len(), 2);
+    assert!(unx.validate().is_ok());
+
+    let unx = UnixString::try_from('🦀').unwrap();
+    assert_eq!(unx.to_str().unwrap(), "🦀");
+    assert_eq!(unx.len(), 4);
+}
+
+#[test]
+fn try_from_nul_char_fails() {
+    assert_eq!(
+        UnixString::try_from('\0').unwrap_err(),
+        Error::InteriorNulByte { position: 0 }
+    );
+}
+
+#[test]
+fn try_from_u8() {
+    let unx = UnixString::try_from(b'/').unwrap();
+    assert_eq!(unx.as_bytes_with_nul(), b"/\0");
+
+    // Non-UTF-8 bytes are fine
+    let unx = UnixString::try_from(0xff_u8).unwrap();
+    assert_eq!(unx.as_bytes_with_nul(), &[0xff, 0]);
+    assert!(unx.validate().is_ok());
+}
+
+#[test]
+fn try_from_zero_u8_fails() {
+    assert_eq!(
+        UnixString::try_from(0_u8).unwrap_err(),
+        Error::InteriorNulByte { position: 0 }
+    );
+}
@@ -1,3 +1,5 @@
+#![cfg(feature = "std")]
+
 use std::{convert::TryFrom, path::PathBuf};
 
 use unixstring::UnixString;
@@ -0,0 +1,42 @@
+use std::convert::TryFrom;
+
+use unixstring::{Error, UnixString};
+
+#[test]
+fn try_from_byte_slice() {
+    let unx = UnixString::try_from(&b"/home/user"[..]).unwrap();
+    assert_eq!(unx.as_bytes_with_nul(), b"/home/user\0");
+    assert_eq!(unx.capacity(), unx.len_with_nul());
+
+    let unx = UnixString::try_from(&b"/home/user\0"[..]).unwrap();
+    assert_eq!(unx.as_bytes_with_nul(), b"/home/user\0");
+
+    let unx = UnixString::try_from(&b""[..]).unwrap();
+    assert!(unx.is_empty());
+    assert!(unx.validate().is_ok());
+
+    let unx = UnixString::try_from(&b"\xff\xfe"[..]).unwrap();
+    assert_eq!(unx.as_bytes_with_nul(), b"\xff\xfe\0");
+}
+
+#[test]
+fn try_from_byte_slice_interior_nul() {
+    assert_eq!(
+        UnixString::try_from(&b"/home\0/user"[..]).unwrap_err(),
+        Error::InteriorNulByte { position: 5 }
+    );
+}
+
+#[test]
+fn try_from_str() {
+    let unx = UnixString::try_from("/home/user").unwrap();
+    assert_eq!(unx.to_str().unwrap(), "/home/user");
+
+    let unx = UnixString::try_from("ünïcödé\0").unwrap();
+    assert_eq!(unx.to_str().unwrap(), "ünïcödé");
+
+    assert_eq!(
+        UnixString::try_from("a\0b").unwrap_err(),
+        Error::InteriorNulByte { position: 1 }
+    );
+}
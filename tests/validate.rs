@@ -1,8 +1,12 @@
-use std::{ffi::CString, path::PathBuf};
+use std::ffi::CString;
+
+#[cfg(feature = "std")]
+use std::path::PathBuf;
 
 use unixstring::UnixString;
 
 #[test]
+#[cfg(feature = "std")]
 fn valid() {
     let logs = PathBuf::from("/var/log");
     let mut logs = UnixString::from_pathbuf(logs).unwrap();
@@ -28,8 +32,25 @@ fn invalid_interior_nul_byte() {
 
     let invalid_unix_strings = UnixString::from_cstring(c);
 
-    assert!(matches!(
-        invalid_unix_strings.validate(),
-        Err(unixstring::Error::InteriorNulByte)
-    ))
+    assert_eq!(
+        invalid_unix_strings.validate().unwrap_err(),
+        unixstring::Error::InteriorNulByte { position: 5 }
+    )
+}
+
+#[test]
+fn missing_nul_terminator_takes_precedence() {
+    let mut unx = UnixString::from_string("hello world".into()).unwrap();
+
+    let ptr = unx.as_mut_ptr();
+    // Add an interior nul byte and overwrite the terminator
+    unsafe {
+        ptr.add(5).write(0);
+        ptr.add(11).write(b'!' as _);
+    }
+
+    assert_eq!(
+        unx.validate().unwrap_err(),
+        unixstring::Error::MissingNulTerminator
+    );
 }
@@ -0,0 +1,94 @@
+use unixstring::UnixString;
+
+#[test]
+fn with_mut_buffer() {
+    let mut unx = UnixString::new();
+
+    unx.with_mut_buffer(10, |buf| {
+        assert_eq!(buf.len(), 10);
+        buf[..5].copy_from_slice(b"hello");
+        5
+    })
+    .unwrap();
+
+    assert_eq!(unx.as_bytes_with_nul(), b"hello\0");
+    assert!(unx.validate().is_ok());
+
+    unx.with_mut_buffer(6, |buf| {
+        buf.copy_from_slice(b" world");
+        6
+    })
+    .unwrap();
+
+    assert_eq!(unx.as_bytes_with_nul(), b"hello world\0");
+    assert!(unx.validate().is_ok());
+}
+
+#[test]
+fn with_mut_buffer_nul_terminated_write() {
+    let mut unx = UnixString::new();
+
+    unx.with_mut_buffer(8, |buf| {
+        buf[..4].copy_from_slice(b"abc\0");
+        4
+    })
+    .unwrap();
+
+    assert_eq!(unx.as_bytes_with_nul(), b"abc\0");
+    assert!(unx.validate().is_ok());
+}
+
+#[test]
+fn with_mut_buffer_nothing_written() {
+    let mut unx = UnixString::from_bytes(b"abc".to_vec()).unwrap();
+
+    unx.with_mut_buffer(8, |_| 0).unwrap();
+
+    assert_eq!(unx.as_bytes_with_nul(), b"abc\0");
+}
+
+#[test]
+fn with_mut_buffer_interior_nul() {
+    let mut unx = UnixString::from_bytes(b"abc".to_vec()).unwrap();
+
+    assert_eq!(
+        unx.with_mut_buffer(8, |buf| {
+            buf[..3].copy_from_slice(b"d\0e");
+            3
+        })
+        .unwrap_err(),
+        unixstring::Error::InteriorNulByte { position: 4 }
+    );
+
+    assert_eq!(unx.as_bytes_with_nul(), b"abc\0");
+    assert!(unx.validate().is_ok());
+}
+
+#[test]
+#[should_panic]
+fn with_mut_buffer_overlong_write() {
+    let mut unx = UnixString::new();
+
+    let _ = unx.with_mut_buffer(4, |_| 5);
+}
+
+#[test]
+fn with_mut_buffer_panicking_closure() {
+    use std::panic::{catch_unwind, AssertUnwindSafe};
+
+    let mut unx = UnixString::from_string("/home".into()).unwrap();
+
+    let result = catch_unwind(AssertUnwindSafe(|| {
+        let _ = unx.with_mut_buffer(4, |_| panic!("FFI call failed"));
+    }));
+    assert!(result.is_err());
+    assert!(unx.validate().is_ok());
+    assert_eq!(unx.as_bytes_with_nul(), b"/home\0");
+
+    let result = catch_unwind(AssertUnwindSafe(|| {
+        let _ = unx.with_mut_buffer(4, |_| 5);
+    }));
+    assert!(result.is_err());
+    assert!(unx.validate().is_ok());
+    assert_eq!(unx.as_bytes_with_nul(), b"/home\0");
+}